@@ -0,0 +1,327 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::{
+    EngineRegistry, FishRegistry, FishingRodRegistry, GameState, MapRegistry, SeasonPassState,
+    ShipRegistry, TidesError, ACCOUNT_RESERVED_BYTES,
+};
+
+// ============ Access Control Constants ============
+pub const MAX_ROLE_MEMBERS: usize = 32;
+
+// ============ Access Control Account Structures ============
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    SuperAdmin,      // 0 - Grants and revokes roles, passes every role check
+    Pauser,          // 1 - Pauses and unpauses the game
+    EconomyManager,  // 2 - Fees, prices and revenue withdrawals
+    RegistryCurator, // 3 - Fish, bait, ship, engine, rod and map registries
+    SeasonManager,   // 4 - Seasons, tracks and leaderboards
+    ShardOperator,   // 5 - Shard creation, limits and player moves
+    Guardian,        // 6 - Vetoes timelocked parameter changes
+}
+
+impl Role {
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Roles that guard the timelock itself, granted and revoked only
+    /// through `propose_change` so Guardians can veto.
+    pub fn is_timelocked(self) -> bool {
+        matches!(self, Role::SuperAdmin | Role::Guardian)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RoleMember {
+    pub account: Pubkey,
+    pub roles: u8, // Bit n set = holds `Role` n
+}
+
+impl RoleMember {
+    pub const LEN: usize = 32 + // account
+        1; // roles
+}
+
+/// Maps pubkeys to the roles they hold. Singleton PDA created by
+/// `initialize`, which makes the game admin the first SuperAdmin.
+#[account]
+pub struct AccessControl {
+    pub members: Vec<RoleMember>,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
+}
+
+impl AccessControl {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_ROLE_MEMBERS * RoleMember::LEN + // members Vec
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+
+    pub fn roles_of(&self, account: &Pubkey) -> u8 {
+        self.members
+            .iter()
+            .find(|member| member.account == *account)
+            .map(|member| member.roles)
+            .unwrap_or(0)
+    }
+
+    pub fn has_role(&self, account: &Pubkey, role: Role) -> bool {
+        let roles = self.roles_of(account);
+        roles & (role.mask() | Role::SuperAdmin.mask()) != 0
+    }
+
+    pub fn require_role(&self, account: &Pubkey, role: Role) -> Result<()> {
+        require!(self.has_role(account, role), TidesError::MissingRole);
+        Ok(())
+    }
+
+    /// Like `require_role`, but being a SuperAdmin is not enough: `account`
+    /// must hold `role` itself. Vetoes use this so that a SuperAdmin cannot
+    /// block changes aimed at them.
+    pub fn require_explicit_role(&self, account: &Pubkey, role: Role) -> Result<()> {
+        require!(self.roles_of(account) & role.mask() != 0, TidesError::MissingRole);
+        Ok(())
+    }
+
+    fn super_admin_count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|member| member.roles & Role::SuperAdmin.mask() != 0)
+            .count()
+    }
+}
+
+/// Accounts whose `admin` is handed over in two steps: the current admin
+/// proposes a successor, who must then accept.
+pub trait TwoStepAdmin {
+    /// `(admin, pending_admin)`
+    fn admins(&mut self) -> (&mut Pubkey, &mut Pubkey);
+}
+
+macro_rules! impl_two_step_admin {
+    ($($account:ty),*) => {
+        $(impl TwoStepAdmin for $account {
+            fn admins(&mut self) -> (&mut Pubkey, &mut Pubkey) {
+                (&mut self.admin, &mut self.pending_admin)
+            }
+        })*
+    };
+}
+
+impl_two_step_admin!(
+    GameState,
+    SeasonPassState,
+    FishRegistry,
+    ShipRegistry,
+    EngineRegistry,
+    FishingRodRegistry,
+    MapRegistry
+);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AdminOp {
+    Propose(Pubkey),
+    Accept,
+    Cancel,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AdminOpResult {
+    pub is_game_state: bool,
+    pub admin: Pubkey, // Admin before the operation
+    pub pending_admin: Pubkey, // Pending admin before the operation
+}
+
+// ============ Access Control Module Functions ============
+
+pub mod access_control {
+    use super::*;
+
+    /// Returns false if `account` already held `role`.
+    pub fn grant_role(
+        access_control: &mut Account<AccessControl>,
+        account: Pubkey,
+        role: Role,
+    ) -> Result<bool> {
+        require!(account != Pubkey::default(), TidesError::InvalidAddress);
+
+        match access_control
+            .members
+            .iter_mut()
+            .find(|member| member.account == account)
+        {
+            Some(member) => {
+                if member.roles & role.mask() != 0 {
+                    return Ok(false);
+                }
+                member.roles |= role.mask();
+            }
+            None => {
+                require!(
+                    access_control.members.len() < MAX_ROLE_MEMBERS,
+                    TidesError::TooManyRoleMembers
+                );
+                access_control.members.push(RoleMember {
+                    account,
+                    roles: role.mask(),
+                });
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Applies `op` to the admin of `target`, which may be the game state,
+    /// the season pass state or any registry. `signer` must be the current
+    /// admin to propose or cancel, and the pending admin to accept.
+    pub fn apply_admin_op(
+        target: &AccountInfo,
+        signer: &Pubkey,
+        op: AdminOp,
+    ) -> Result<AdminOpResult> {
+        require!(target.owner == &crate::ID, TidesError::InvalidAddress);
+
+        let discriminator: [u8; 8] = target
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+
+        let result = match discriminator {
+            GameState::DISCRIMINATOR => {
+                require_keys_eq!(*target.key, GameState::address(), TidesError::InvalidAddress);
+                apply::<GameState>(target, signer, op)?
+            }
+            SeasonPassState::DISCRIMINATOR => apply::<SeasonPassState>(target, signer, op)?,
+            FishRegistry::DISCRIMINATOR => apply::<FishRegistry>(target, signer, op)?,
+            ShipRegistry::DISCRIMINATOR => apply::<ShipRegistry>(target, signer, op)?,
+            EngineRegistry::DISCRIMINATOR => apply::<EngineRegistry>(target, signer, op)?,
+            FishingRodRegistry::DISCRIMINATOR => apply::<FishingRodRegistry>(target, signer, op)?,
+            MapRegistry::DISCRIMINATOR => apply::<MapRegistry>(target, signer, op)?,
+            _ => return err!(TidesError::InvalidAddress),
+        };
+
+        Ok(AdminOpResult {
+            is_game_state: discriminator == GameState::DISCRIMINATOR,
+            ..result
+        })
+    }
+
+    fn apply<T>(target: &AccountInfo, signer: &Pubkey, op: AdminOp) -> Result<AdminOpResult>
+    where
+        T: AccountSerialize + AccountDeserialize + TwoStepAdmin,
+    {
+        let mut data = target.try_borrow_mut_data()?;
+        let mut account = T::try_deserialize(&mut &data[..])?;
+        let (admin, pending_admin) = account.admins();
+        let result = AdminOpResult {
+            is_game_state: false,
+            admin: *admin,
+            pending_admin: *pending_admin,
+        };
+
+        match op {
+            AdminOp::Propose(new_admin) => {
+                require!(*admin == *signer, TidesError::InvalidAddress);
+                require!(
+                    new_admin != Pubkey::default() && new_admin != *admin,
+                    TidesError::InvalidAddress
+                );
+                *pending_admin = new_admin;
+            }
+            AdminOp::Accept => {
+                require!(*pending_admin != Pubkey::default(), TidesError::NoPendingAdmin);
+                require!(*pending_admin == *signer, TidesError::InvalidAddress);
+                *admin = *pending_admin;
+                *pending_admin = Pubkey::default();
+            }
+            AdminOp::Cancel => {
+                require!(*admin == *signer, TidesError::InvalidAddress);
+                require!(*pending_admin != Pubkey::default(), TidesError::NoPendingAdmin);
+                *pending_admin = Pubkey::default();
+            }
+        }
+
+        let mut writer: &mut [u8] = &mut data[..];
+        account.try_serialize(&mut writer)?;
+
+        Ok(result)
+    }
+
+    /// Returns false if `account` did not hold `role`. Members left without
+    /// any role are removed, and the last SuperAdmin cannot be revoked.
+    pub fn revoke_role(
+        access_control: &mut Account<AccessControl>,
+        account: Pubkey,
+        role: Role,
+    ) -> Result<bool> {
+        if access_control.roles_of(&account) & role.mask() == 0 {
+            return Ok(false);
+        }
+        if role == Role::SuperAdmin {
+            require!(
+                access_control.super_admin_count() > 1,
+                TidesError::LastSuperAdmin
+            );
+        }
+
+        for member in access_control.members.iter_mut() {
+            if member.account == account {
+                member.roles &= !role.mask();
+            }
+        }
+        access_control.members.retain(|member| member.roles != 0);
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    fn roles(members: Vec<RoleMember>) -> AccessControl {
+        AccessControl {
+            members,
+            version: 0,
+            _reserved: [0; ACCOUNT_RESERVED_BYTES],
+        }
+    }
+
+    #[test]
+    fn super_admin_passes_role_checks() {
+        let access_control = roles(vec![RoleMember {
+            account: key(1),
+            roles: Role::SuperAdmin.mask(),
+        }]);
+
+        assert!(access_control.require_role(&key(1), Role::SeasonManager).is_ok());
+        assert!(access_control.require_role(&key(2), Role::SeasonManager).is_err());
+    }
+
+    #[test]
+    fn veto_needs_guardian_role_itself() {
+        let access_control = roles(vec![
+            RoleMember {
+                account: key(1),
+                roles: Role::SuperAdmin.mask(),
+            },
+            RoleMember {
+                account: key(2),
+                roles: Role::Guardian.mask(),
+            },
+        ]);
+
+        assert_eq!(
+            access_control.require_explicit_role(&key(1), Role::Guardian).err(),
+            Some(TidesError::MissingRole.into())
+        );
+        assert!(access_control.require_explicit_role(&key(2), Role::Guardian).is_ok());
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{
+    PAUSE_INVENTORY, PAUSE_MOVEMENT, PAUSE_SHOP, SESSION_INVENTORY, SESSION_MOVE,
+    SESSION_PURCHASE_FUEL,
+};
+
+// ============ Action Constants ============
+pub const MAX_BATCH_ACTIONS: usize = 16;
+
+// ============ Action Types ============
+
+/// One step of an `execute_actions` batch. Each variant does the same as
+/// the single instruction it is named after.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum GameAction {
+    Move {
+        directions: Vec<u8>,
+    },
+    InventoryMove {
+        from_x: u8,
+        from_y: u8,
+        to_x: u8,
+        to_y: u8,
+        rotation: u8,
+    },
+    Discard {
+        x: u8,
+        y: u8,
+    },
+    PurchaseFuel {
+        amount: u64,
+    },
+}
+
+impl GameAction {
+    /// `PAUSE_*` flag of the subsystem the action belongs to.
+    pub fn pause_flag(&self) -> u8 {
+        match self {
+            GameAction::Move { .. } => PAUSE_MOVEMENT,
+            GameAction::InventoryMove { .. } | GameAction::Discard { .. } => PAUSE_INVENTORY,
+            GameAction::PurchaseFuel { .. } => PAUSE_SHOP,
+        }
+    }
+
+    /// `SESSION_*` bit a session key needs for the action.
+    pub fn session_permission(&self) -> u16 {
+        match self {
+            GameAction::Move { .. } => SESSION_MOVE,
+            GameAction::InventoryMove { .. } | GameAction::Discard { .. } => SESSION_INVENTORY,
+            GameAction::PurchaseFuel { .. } => SESSION_PURCHASE_FUEL,
+        }
+    }
+}
+
+/// Whether `actions` fits in one batch: between one and `MAX_BATCH_ACTIONS`
+/// actions, with at most one `Move`, since a move starts the cooldown the
+/// next one would have to wait out.
+pub fn is_valid_batch(actions: &[GameAction]) -> bool {
+    let moves = actions
+        .iter()
+        .filter(|action| matches!(action, GameAction::Move { .. }))
+        .count();
+    !actions.is_empty() && actions.len() <= MAX_BATCH_ACTIONS && moves <= 1
+}
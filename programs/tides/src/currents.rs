@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use serde::{Deserialize, Serialize};
+use crate::hex::hex;
+use crate::{Hex, HexDirection, Map, TidesError, HEX_MOVE_COST};
+
+// ============ Current Constants ============
+pub const CURRENT_GRID_SIZE: usize = 4; // Maps are split into 4x4 current regions
+pub const CURRENT_REGIONS: usize = CURRENT_GRID_SIZE * CURRENT_GRID_SIZE;
+pub const MAX_CURRENT_STRENGTH: u8 = 50; // % of HEX_MOVE_COST saved sailing straight with it
+pub const MAX_WIND_STRENGTH: u8 = 20; // % of HEX_MOVE_COST, like currents
+pub const WIND_PERIOD: i64 = 3_600; // Wind changes every hour
+// Cheapest possible step, sailing with both the strongest current and wind
+pub const MIN_STEP_COST: u64 =
+    HEX_MOVE_COST * (100 - MAX_CURRENT_STRENGTH as u64 - MAX_WIND_STRENGTH as u64) / 100;
+
+// ============ Current Types ============
+
+/// Flow of water (or air) in one direction. Zero strength is calm.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default,
+)]
+pub struct Current {
+    pub direction: u8, // HexDirection index the flow runs towards
+    pub strength: u8,  // % of HEX_MOVE_COST
+}
+
+impl Current {
+    pub const LEN: usize = 1 + // direction
+        1; // strength
+}
+
+// ============ Current Module Functions ============
+
+pub mod currents {
+    use super::*;
+
+    pub fn validate_currents(currents: &[Current]) -> Result<()> {
+        for current in currents {
+            require!(
+                HexDirection::from_index(current.direction).is_some()
+                    && current.strength <= MAX_CURRENT_STRENGTH,
+                TidesError::InvalidCurrent
+            );
+        }
+        Ok(())
+    }
+
+    /// Index into `Map.currents` of the region holding `hex`, or `None`
+    /// off the map.
+    pub fn region_of(map: &Map, hex: Hex) -> Option<usize> {
+        if !(map.min_x..=map.max_x).contains(&hex.q) || !(map.min_y..=map.max_y).contains(&hex.r) {
+            return None;
+        }
+        let cell = |value: i32, min: i32, max: i32| {
+            let span = max as i64 - min as i64 + 1;
+            ((value as i64 - min as i64) * CURRENT_GRID_SIZE as i64 / span) as usize
+        };
+        let column = cell(hex.q, map.min_x, map.max_x);
+        let row = cell(hex.r, map.min_y, map.max_y);
+        Some(row * CURRENT_GRID_SIZE + column)
+    }
+
+    /// Current flowing through `hex`; calm off the map.
+    pub fn current_at(map: &Map, hex: Hex) -> Current {
+        region_of(map, hex)
+            .map(|region| map.currents[region])
+            .unwrap_or_default()
+    }
+
+    /// Wind over the whole map during the `WIND_PERIOD` holding `now`.
+    pub fn wind_at(map_id: u64, now: i64) -> Current {
+        let period = now.div_euclid(WIND_PERIOD);
+        let hash = keccak::hashv(&[b"wind", &map_id.to_le_bytes(), &period.to_le_bytes()]).0;
+        Current {
+            direction: hash[0] % 6,
+            strength: hash[1] % (MAX_WIND_STRENGTH + 1),
+        }
+    }
+
+    /// How much of a flow's strength helps a step: 2 sailing straight with
+    /// it, 1 at 60 degrees, -1 at 120 and -2 straight against it.
+    fn alignment(heading: HexDirection, flow: &Current) -> i64 {
+        let turn = (heading.index() as i64 - flow.direction as i64).rem_euclid(6);
+        match turn.min(6 - turn) {
+            0 => 2,
+            1 => 1,
+            2 => -1,
+            _ => -2,
+        }
+    }
+
+    /// Fuel `move_player` charges for one step from `from` towards
+    /// `heading` at `now`. The current is the one of the hex being left.
+    pub fn step_cost(map: &Map, from: Hex, heading: HexDirection, now: i64) -> u64 {
+        let current = current_at(map, from);
+        let wind = wind_at(map.map_id, now);
+        // In half percents, so 60 degree headings keep half the strength exactly
+        let assist = alignment(heading, &current) * current.strength as i64
+            + alignment(heading, &wind) * wind.strength as i64;
+        HEX_MOVE_COST * (200 - assist) as u64 / 200
+    }
+
+    /// Fuel for sailing `directions` from `start` at `now`, as charged by
+    /// `move_player`.
+    pub fn route_cost(map: &Map, start: Hex, directions: &[u8], now: i64) -> Result<u64> {
+        let mut position = start;
+        let mut total: u64 = 0;
+        for heading in hex::parse_directions(directions)? {
+            total = total
+                .checked_add(step_cost(map, position, heading, now))
+                .ok_or(TidesError::MathOverflow)?;
+            position = position.step(heading)?;
+        }
+        Ok(total)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::ACCOUNT_RESERVED_BYTES;
+
+        fn map_with_current(current: Current) -> Map {
+            Map {
+                registry: Pubkey::default(),
+                map_id: 7,
+                travel_cost: 0,
+                min_x: -10,
+                max_x: 9,
+                min_y: -10,
+                max_y: 9,
+                harbors: vec![],
+                version: 0,
+                currents: [current; CURRENT_REGIONS],
+                _reserved: [0; ACCOUNT_RESERVED_BYTES - CURRENT_REGIONS * Current::LEN],
+            }
+        }
+
+        // A time whose wind is calm, so costs come from the current alone
+        fn calm_time(map_id: u64) -> i64 {
+            (0..)
+                .map(|period| period * WIND_PERIOD)
+                .find(|now| wind_at(map_id, *now).strength == 0)
+                .unwrap()
+        }
+
+        #[test]
+        fn regions_cover_the_map() {
+            let map = map_with_current(Current::default());
+            assert_eq!(region_of(&map, Hex::new(-10, -10)), Some(0));
+            assert_eq!(region_of(&map, Hex::new(9, -10)), Some(CURRENT_GRID_SIZE - 1));
+            assert_eq!(region_of(&map, Hex::new(-10, 9)), Some(CURRENT_REGIONS - CURRENT_GRID_SIZE));
+            assert_eq!(region_of(&map, Hex::new(9, 9)), Some(CURRENT_REGIONS - 1));
+            assert_eq!(region_of(&map, Hex::new(-6, -10)), Some(0));
+            assert_eq!(region_of(&map, Hex::new(-5, -10)), Some(1));
+            assert_eq!(region_of(&map, Hex::new(10, 0)), None);
+            assert_eq!(region_of(&map, Hex::new(0, -11)), None);
+        }
+
+        #[test]
+        fn current_lowers_cost_downstream_and_raises_it_upstream() {
+            let map = map_with_current(Current { direction: 0, strength: 40 });
+            let now = calm_time(map.map_id);
+            let cost = |heading| step_cost(&map, Hex::ORIGIN, heading, now);
+            assert_eq!(cost(HexDirection::East), HEX_MOVE_COST * 60 / 100);
+            assert_eq!(cost(HexDirection::NorthEast), HEX_MOVE_COST * 80 / 100);
+            assert_eq!(cost(HexDirection::SouthEast), HEX_MOVE_COST * 80 / 100);
+            assert_eq!(cost(HexDirection::NorthWest), HEX_MOVE_COST * 120 / 100);
+            assert_eq!(cost(HexDirection::West), HEX_MOVE_COST * 140 / 100);
+
+            // Off the map the water is calm
+            assert_eq!(step_cost(&map, Hex::new(50, 0), HexDirection::East, now), HEX_MOVE_COST);
+        }
+
+        #[test]
+        fn wind_is_deterministic_per_period() {
+            for now in [0, 1_700_000_000, -5] {
+                let wind = wind_at(3, now);
+                assert!(wind.direction < 6 && wind.strength <= MAX_WIND_STRENGTH);
+                let period_start = now.div_euclid(WIND_PERIOD) * WIND_PERIOD;
+                assert_eq!(wind_at(3, period_start), wind);
+                assert_eq!(wind_at(3, period_start + WIND_PERIOD - 1), wind);
+            }
+            let winds: Vec<Current> = (0..50).map(|period| wind_at(3, period * WIND_PERIOD)).collect();
+            assert!(winds.windows(2).any(|pair| pair[0] != pair[1]));
+        }
+
+        #[test]
+        fn costs_stay_within_bounds() {
+            for direction in 0..6 {
+                let map = map_with_current(Current { direction, strength: MAX_CURRENT_STRENGTH });
+                for period in 0..20 {
+                    for heading in HexDirection::ALL {
+                        let cost = step_cost(&map, Hex::ORIGIN, heading, period * WIND_PERIOD);
+                        assert!(cost >= MIN_STEP_COST);
+                        assert!(cost <= HEX_MOVE_COST * 170 / 100);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn route_cost_sums_steps() {
+            let map = map_with_current(Current { direction: 1, strength: 25 });
+            let now = 1_700_000_000;
+            let directions = [0, 1, 1, 4, 3];
+            let mut expected = 0;
+            let mut position = Hex::ORIGIN;
+            for heading in hex::parse_directions(&directions).unwrap() {
+                expected += step_cost(&map, position, heading, now);
+                position = position.step(heading).unwrap();
+            }
+            assert_eq!(route_cost(&map, Hex::ORIGIN, &directions, now).unwrap(), expected);
+            assert!(route_cost(&map, Hex::ORIGIN, &[6], now).is_err());
+            assert!(validate_currents(&[Current { direction: 6, strength: 0 }]).is_err());
+            assert!(validate_currents(&[Current { direction: 0, strength: MAX_CURRENT_STRENGTH + 1 }]).is_err());
+            assert!(validate_currents(&[Current { direction: 5, strength: MAX_CURRENT_STRENGTH }]).is_ok());
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak;
-use crate::TidesError;
+use anchor_lang::solana_program::{ed25519_program, sysvar::instructions};
+use crate::{TidesError, ACCOUNT_RESERVED_BYTES};
 
 // ============ Fishing Constants ============
 pub const SIGNATURE_TIMEOUT: i64 = 300; // 5 minutes
+pub const MAX_ORACLE_SIGNERS: usize = 8;
+pub const ORACLE_ROTATION_GRACE: i64 = 3_600; // Retired keys stay valid for 1 hour
+pub const FISHING_RESULT_DOMAIN: &[u8] = b"tides:fishing_result";
 
 // ============ Fishing Account Structures ============
 
@@ -13,6 +17,8 @@ pub struct FishingState {
     pub pending_nonce: u64,
     pub bait_type_used: u64,
     pub fishing_nonce: u64,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl FishingState {
@@ -20,7 +26,9 @@ impl FishingState {
         32 + // player
         8 + // pending_nonce
         8 + // bait_type_used
-        8; // fishing_nonce
+        8 + // fishing_nonce
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
 }
 
 #[account]
@@ -28,13 +36,74 @@ pub struct PlayerBait {
     pub player: Pubkey,
     pub bait_type: u64,
     pub amount: u64,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl PlayerBait {
     pub const LEN: usize = 8 + // discriminator
         32 + // player
         8 + // bait_type
-        8; // amount
+        8 + // amount
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+
+    /// Address of `player`'s bait PDA for `bait_type`.
+    pub fn address(player: &Pubkey, bait_type: u64) -> Pubkey {
+        Pubkey::find_program_address(&[b"bait", player.as_ref(), &bait_type.to_le_bytes()], &crate::ID).0
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OracleSigner {
+    pub key: Pubkey,
+    pub valid_from: i64,
+    pub valid_until: i64, // i64::MAX until the key is retired
+}
+
+impl OracleSigner {
+    pub const LEN: usize = 32 + // key
+        8 + // valid_from
+        8; // valid_until
+
+    pub fn is_valid_at(&self, timestamp: i64) -> bool {
+        timestamp >= self.valid_from && timestamp <= self.valid_until
+    }
+}
+
+/// Keys allowed to sign fishing results. Singleton PDA created by
+/// `initialize` with the server signer, changed only through governance.
+#[account]
+pub struct OracleSet {
+    pub signers: Vec<OracleSigner>,
+    pub cosign_min_base_price: u64, // Species at or above this base price need co-signing, 0 = off
+    pub cosign_required: u8, // Distinct signers needed for those species
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
+}
+
+impl OracleSet {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_ORACLE_SIGNERS * OracleSigner::LEN + // signers Vec
+        8 + // cosign_min_base_price
+        1 + // cosign_required
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+
+    pub fn is_valid_signer(&self, key: &Pubkey, timestamp: i64) -> bool {
+        self.signers
+            .iter()
+            .any(|signer| signer.key == *key && signer.is_valid_at(timestamp))
+    }
+
+    /// Signatures needed for a catch of a species with `base_price`.
+    pub fn required_signatures(&self, base_price: u64) -> u8 {
+        if self.cosign_min_base_price > 0 && base_price >= self.cosign_min_base_price {
+            self.cosign_required.max(1)
+        } else {
+            1
+        }
+    }
 }
 
 // ============ Fishing Structs ============
@@ -82,10 +151,27 @@ pub mod fishing {
         Ok(fishing_nonce)
     }
 
-    pub fn verify_fishing_signature(
+    /// Bytes the oracles sign: `FISHING_RESULT_DOMAIN`, the program ID and
+    /// the `game_state` key, followed by the Borsh-encoded `FishingResult`.
+    /// Results signed for another deployment or game don't verify here.
+    pub fn fishing_result_message(result: &FishingResult, game_state: &Pubkey) -> Result<Vec<u8>> {
+        let mut message = FISHING_RESULT_DOMAIN.to_vec();
+        message.extend_from_slice(crate::ID.as_ref());
+        message.extend_from_slice(game_state.as_ref());
+        result.serialize(&mut message)?;
+        Ok(message)
+    }
+
+    /// Checks that `required` distinct oracle keys, valid now, signed
+    /// `result` for `game_state`. Signatures are verified by Ed25519 program instructions in
+    /// the same transaction; this reads them back from the instructions
+    /// sysvar and only trusts data embedded in those instructions.
+    pub fn verify_fishing_signatures(
         result: &FishingResult,
-        signature: &[u8],
-        server_signer: &Pubkey,
+        game_state: &Pubkey,
+        oracle_set: &OracleSet,
+        instructions_sysvar: &AccountInfo,
+        required: u8,
         clock: &Clock,
     ) -> Result<()> {
         // Verify timestamp is recent
@@ -98,13 +184,125 @@ pub mod fishing {
             TidesError::FutureTimestamp
         );
 
-        // In a full implementation, verify Ed25519 signature
-        // For now, this is a placeholder
-        // The signature verification would use solana_program::ed25519_program
+        let message = fishing_result_message(result, game_state)?;
+        let mut signers: Vec<Pubkey> = Vec::new();
+        let mut index = 0;
+        while let Ok(ix) = instructions::load_instruction_at_checked(index, instructions_sysvar) {
+            index += 1;
+            if ix.program_id != ed25519_program::ID {
+                continue;
+            }
+            for key in ed25519_signers(&ix.data, &message)? {
+                if oracle_set.is_valid_signer(&key, clock.unix_timestamp) && !signers.contains(&key) {
+                    signers.push(key);
+                }
+            }
+        }
 
+        require!(
+            signers.len() >= required as usize,
+            TidesError::InsufficientOracleSignatures
+        );
         Ok(())
     }
 
+    /// Public keys of the signatures in an Ed25519 program instruction that
+    /// cover `message`.
+    fn ed25519_signers(data: &[u8], message: &[u8]) -> Result<Vec<Pubkey>> {
+        const HEADER_LEN: usize = 2;
+        const OFFSETS_LEN: usize = 14;
+        const THIS_INSTRUCTION: u16 = u16::MAX;
+
+        let read_u16 = |at: usize| -> Result<u16> {
+            data.get(at..at + 2)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+                .ok_or_else(|| error!(TidesError::InvalidFishingResult))
+        };
+
+        let count = *data.first().ok_or(TidesError::InvalidFishingResult)? as usize;
+        let mut keys = Vec::with_capacity(count);
+        for i in 0..count {
+            let base = HEADER_LEN + i * OFFSETS_LEN;
+            let public_key_offset = read_u16(base + 4)? as usize;
+            let public_key_ix = read_u16(base + 6)?;
+            let message_offset = read_u16(base + 8)? as usize;
+            let message_size = read_u16(base + 10)? as usize;
+            let message_ix = read_u16(base + 12)?;
+
+            // Offsets pointing at other instructions could smuggle in data
+            // the Ed25519 program never saw next to this signature
+            if public_key_ix != THIS_INSTRUCTION || message_ix != THIS_INSTRUCTION {
+                continue;
+            }
+
+            let signed = data
+                .get(message_offset..message_offset + message_size)
+                .ok_or(TidesError::InvalidFishingResult)?;
+            if signed != message {
+                continue;
+            }
+
+            let key = data
+                .get(public_key_offset..public_key_offset + 32)
+                .ok_or(TidesError::InvalidFishingResult)?;
+            keys.push(Pubkey::try_from(key).map_err(|_| TidesError::InvalidFishingResult)?);
+        }
+        Ok(keys)
+    }
+
+    /// Adds `key` as a signer valid from `now`, dropping signers whose
+    /// window has already closed.
+    pub fn add_oracle_signer(oracle_set: &mut Account<OracleSet>, key: Pubkey, now: i64) -> Result<()> {
+        oracle_set.signers.retain(|signer| signer.valid_until >= now);
+        require!(
+            !oracle_set.signers.iter().any(|signer| signer.key == key),
+            TidesError::OracleSignerExists
+        );
+        require!(
+            oracle_set.signers.len() < MAX_ORACLE_SIGNERS,
+            TidesError::TooManyOracleSigners
+        );
+
+        oracle_set.signers.push(OracleSigner {
+            key,
+            valid_from: now,
+            valid_until: i64::MAX,
+        });
+        Ok(())
+    }
+
+    /// Stops accepting `key` after `now + ORACLE_ROTATION_GRACE`, so results
+    /// it already signed can still be fulfilled.
+    pub fn retire_oracle_signer(oracle_set: &mut Account<OracleSet>, key: Pubkey, now: i64) -> Result<()> {
+        let cutoff = now
+            .checked_add(ORACLE_ROTATION_GRACE)
+            .ok_or(TidesError::MathOverflow)?;
+        let signer = oracle_set
+            .signers
+            .iter_mut()
+            .find(|signer| signer.key == key && signer.valid_until > cutoff)
+            .ok_or(TidesError::OracleSignerNotFound)?;
+        signer.valid_until = cutoff;
+
+        require!(
+            oracle_set.signers.iter().any(|signer| signer.valid_until == i64::MAX),
+            TidesError::NoActiveOracleSigner
+        );
+        Ok(())
+    }
+
+    /// Replaces every open signer with `new_key`. The old keys keep working
+    /// for the rotation grace period.
+    pub fn rotate_oracle_signer(oracle_set: &mut Account<OracleSet>, new_key: Pubkey, now: i64) -> Result<()> {
+        let cutoff = now
+            .checked_add(ORACLE_ROTATION_GRACE)
+            .ok_or(TidesError::MathOverflow)?;
+        for signer in oracle_set.signers.iter_mut() {
+            signer.valid_until = signer.valid_until.min(cutoff);
+        }
+        add_oracle_signer(oracle_set, new_key, now)
+    }
+
     pub fn fulfill_fishing(
         fishing_state: &mut Account<FishingState>,
         result: &FishingResult,
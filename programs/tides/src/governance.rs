@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use crate::access_control::access_control;
+use crate::fishing::fishing;
+use crate::{
+    AccessControl, GameState, OracleSet, Role, RoleGranted, RoleRevoked, SeasonPassState,
+    TidesError, ACCOUNT_RESERVED_BYTES, ACCOUNT_VERSION, MAX_ORACLE_SIGNERS,
+};
+
+// ============ Governance Constants ============
+pub const DEFAULT_TIMELOCK_DELAY: i64 = 86_400; // 24 hours
+pub const MIN_TIMELOCK_DELAY: i64 = 3_600; // 1 hour
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 86_400; // 30 days
+pub const CHANGE_EXECUTION_WINDOW: i64 = 7 * 86_400; // Changes expire a week after they become executable
+
+// ============ Governance Account Structures ============
+
+/// A sensitive parameter change. Applied only through `execute_change`
+/// once its timelock has passed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParameterChange {
+    ServerSigner(Pubkey), // Rotates every open oracle key to this one
+    AddOracleSigner(Pubkey),
+    RetireOracleSigner(Pubkey),
+    CosignPolicy(u64, u8), // (min base price, signers required)
+    MaxPlayersPerShard(u64),
+    ShardChangeFee(u64),
+    Treasury(Pubkey), // SeasonPassState.treasury
+    TimelockDelay(i64),
+    GrantSuperAdmin(Pubkey),
+    RevokeSuperAdmin(Pubkey),
+    GrantGuardian(Pubkey),
+    RevokeGuardian(Pubkey),
+}
+
+impl ParameterChange {
+    pub const LEN: usize = 1 + 32; // variant + largest payload
+
+    /// Role needed to propose this change.
+    pub fn required_role(&self) -> Role {
+        match self {
+            ParameterChange::ServerSigner(_) => Role::SuperAdmin,
+            ParameterChange::AddOracleSigner(_) => Role::SuperAdmin,
+            ParameterChange::RetireOracleSigner(_) => Role::SuperAdmin,
+            ParameterChange::CosignPolicy(_, _) => Role::SuperAdmin,
+            ParameterChange::MaxPlayersPerShard(_) => Role::ShardOperator,
+            ParameterChange::ShardChangeFee(_) => Role::EconomyManager,
+            ParameterChange::Treasury(_) => Role::EconomyManager,
+            ParameterChange::TimelockDelay(_) => Role::SuperAdmin,
+            ParameterChange::GrantSuperAdmin(_) => Role::SuperAdmin,
+            ParameterChange::RevokeSuperAdmin(_) => Role::SuperAdmin,
+            ParameterChange::GrantGuardian(_) => Role::SuperAdmin,
+            ParameterChange::RevokeGuardian(_) => Role::SuperAdmin,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChangeStatus {
+    Pending,
+    Executed,
+    Vetoed,
+}
+
+#[account]
+pub struct PendingChange {
+    pub change_id: u64,
+    pub change: ParameterChange,
+    pub proposer: Pubkey,
+    pub proposed_at: i64,
+    pub executable_at: i64,
+    pub status: ChangeStatus,
+    pub version: u8,
+    pub expires_at: i64, // No longer executable after this
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 8],
+}
+
+impl PendingChange {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // change_id
+        ParameterChange::LEN + // change
+        32 + // proposer
+        8 + // proposed_at
+        8 + // executable_at
+        1 + // status
+        1 + // version
+        8 + // expires_at
+        ACCOUNT_RESERVED_BYTES - 8; // _reserved
+}
+
+// ============ Governance Module Functions ============
+
+pub mod governance {
+    use super::*;
+
+    pub fn validate_change(change: &ParameterChange) -> Result<()> {
+        match *change {
+            ParameterChange::ServerSigner(signer) | ParameterChange::AddOracleSigner(signer) => {
+                require!(signer != Pubkey::default(), TidesError::InvalidAddress);
+            }
+            ParameterChange::RetireOracleSigner(_) => {}
+            ParameterChange::CosignPolicy(_, required) => {
+                require!(
+                    required > 0 && required as usize <= MAX_ORACLE_SIGNERS,
+                    TidesError::LimitOutOfBounds
+                );
+            }
+            ParameterChange::MaxPlayersPerShard(limit) => {
+                require!(limit > 0 && limit <= 10000, TidesError::LimitOutOfBounds);
+            }
+            ParameterChange::ShardChangeFee(_) => {}
+            ParameterChange::Treasury(treasury) => {
+                require!(treasury != Pubkey::default(), TidesError::InvalidAddress);
+            }
+            ParameterChange::TimelockDelay(delay) => {
+                require!(
+                    (MIN_TIMELOCK_DELAY..=MAX_TIMELOCK_DELAY).contains(&delay),
+                    TidesError::LimitOutOfBounds
+                );
+            }
+            ParameterChange::GrantSuperAdmin(account)
+            | ParameterChange::RevokeSuperAdmin(account)
+            | ParameterChange::GrantGuardian(account)
+            | ParameterChange::RevokeGuardian(account) => {
+                require!(account != Pubkey::default(), TidesError::InvalidAddress);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn queue_change(
+        pending_change: &mut Account<PendingChange>,
+        game_state: &mut Account<GameState>,
+        change: ParameterChange,
+        proposer: Pubkey,
+        clock: &Clock,
+    ) -> Result<()> {
+        validate_change(&change)?;
+
+        pending_change.change_id = game_state.next_change_id;
+        pending_change.change = change;
+        pending_change.proposer = proposer;
+        pending_change.proposed_at = clock.unix_timestamp;
+        pending_change.executable_at = clock
+            .unix_timestamp
+            .checked_add(game_state.timelock_delay)
+            .ok_or(TidesError::MathOverflow)?;
+        pending_change.expires_at = pending_change
+            .executable_at
+            .checked_add(CHANGE_EXECUTION_WINDOW)
+            .ok_or(TidesError::MathOverflow)?;
+        pending_change.status = ChangeStatus::Pending;
+        pending_change.version = ACCOUNT_VERSION;
+
+        game_state.next_change_id = game_state
+            .next_change_id
+            .checked_add(1)
+            .ok_or(TidesError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn apply_change(
+        pending_change: &PendingChange,
+        game_state: &mut Account<GameState>,
+        season_pass_state: Option<&mut Account<SeasonPassState>>,
+        oracle_set: Option<&mut Account<OracleSet>>,
+        roles: Option<&mut Account<AccessControl>>,
+        clock: &Clock,
+    ) -> Result<()> {
+        let now = clock.unix_timestamp;
+        match pending_change.change {
+            ParameterChange::ServerSigner(signer) => {
+                let oracle_set = oracle_set.ok_or(TidesError::OracleSetRequired)?;
+                fishing::rotate_oracle_signer(oracle_set, signer, now)?;
+                game_state.server_signer = signer;
+            }
+            ParameterChange::AddOracleSigner(signer) => {
+                let oracle_set = oracle_set.ok_or(TidesError::OracleSetRequired)?;
+                fishing::add_oracle_signer(oracle_set, signer, now)?;
+            }
+            ParameterChange::RetireOracleSigner(signer) => {
+                let oracle_set = oracle_set.ok_or(TidesError::OracleSetRequired)?;
+                fishing::retire_oracle_signer(oracle_set, signer, now)?;
+            }
+            ParameterChange::CosignPolicy(min_base_price, required) => {
+                let oracle_set = oracle_set.ok_or(TidesError::OracleSetRequired)?;
+                oracle_set.cosign_min_base_price = min_base_price;
+                oracle_set.cosign_required = required;
+            }
+            ParameterChange::MaxPlayersPerShard(limit) => game_state.max_players_per_shard = limit,
+            ParameterChange::ShardChangeFee(fee) => game_state.shard_change_fee = fee,
+            ParameterChange::Treasury(treasury) => {
+                let season_pass_state =
+                    season_pass_state.ok_or(TidesError::SeasonPassStateRequired)?;
+                season_pass_state.treasury = treasury;
+            }
+            ParameterChange::TimelockDelay(delay) => game_state.timelock_delay = delay,
+            ParameterChange::GrantSuperAdmin(account) => {
+                grant(roles, account, Role::SuperAdmin, pending_change.proposer)?
+            }
+            ParameterChange::RevokeSuperAdmin(account) => {
+                revoke(roles, account, Role::SuperAdmin, pending_change.proposer)?
+            }
+            ParameterChange::GrantGuardian(account) => {
+                grant(roles, account, Role::Guardian, pending_change.proposer)?
+            }
+            ParameterChange::RevokeGuardian(account) => {
+                revoke(roles, account, Role::Guardian, pending_change.proposer)?
+            }
+        }
+        Ok(())
+    }
+
+    fn grant(
+        roles: Option<&mut Account<AccessControl>>,
+        account: Pubkey,
+        role: Role,
+        proposer: Pubkey,
+    ) -> Result<()> {
+        let roles = roles.ok_or(TidesError::AccessControlRequired)?;
+        if access_control::grant_role(roles, account, role)? {
+            emit!(RoleGranted {
+                account,
+                role,
+                granted_by: proposer,
+            });
+        }
+        Ok(())
+    }
+
+    fn revoke(
+        roles: Option<&mut Account<AccessControl>>,
+        account: Pubkey,
+        role: Role,
+        proposer: Pubkey,
+    ) -> Result<()> {
+        let roles = roles.ok_or(TidesError::AccessControlRequired)?;
+        if access_control::revoke_role(roles, account, role)? {
+            emit!(RoleRevoked {
+                account,
+                role,
+                revoked_by: proposer,
+            });
+        }
+        Ok(())
+    }
+}
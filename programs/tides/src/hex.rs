@@ -0,0 +1,550 @@
+use anchor_lang::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use crate::TidesError;
+
+// ============ Hex Types ============
+
+/// Axial coordinate on the pointy-top hex grid. `q` is a player's
+/// `position_x` and `r` their `position_y`.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Default,
+)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+/// Cube form of a hex, with `q + r + s == 0`.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Default,
+)]
+pub struct CubeHex {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+/// Direction of a single step. The discriminant is the byte clients send in
+/// `move_player` and `GameAction::Move`.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug,
+)]
+#[repr(u8)]
+pub enum HexDirection {
+    East = 0,
+    NorthEast = 1,
+    NorthWest = 2,
+    West = 3,
+    SouthWest = 4,
+    SouthEast = 5,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Hex { q, r }
+    }
+
+    pub fn to_cube(self) -> CubeHex {
+        CubeHex::from(self)
+    }
+
+    /// The adjacent hex in `direction`, or `None` past the `i32` range.
+    pub fn neighbor(self, direction: HexDirection) -> Option<Hex> {
+        let (dq, dr) = direction.offset();
+        Some(Hex::new(self.q.checked_add(dq)?, self.r.checked_add(dr)?))
+    }
+
+    /// Like `neighbor`, failing with `MathOverflow` instead of `None`.
+    pub fn step(self, direction: HexDirection) -> Result<Hex> {
+        Ok(self.neighbor(direction).ok_or(TidesError::MathOverflow)?)
+    }
+
+    /// Adjacent hexes in `HexDirection::ALL` order, skipping any past the
+    /// `i32` range.
+    pub fn neighbors(self) -> Vec<Hex> {
+        HexDirection::ALL
+            .iter()
+            .filter_map(|direction| self.neighbor(*direction))
+            .collect()
+    }
+
+    /// Number of steps between the two hexes.
+    pub fn distance(self, other: Hex) -> u64 {
+        let dq = other.q as i64 - self.q as i64;
+        let dr = other.r as i64 - self.r as i64;
+        (dq.unsigned_abs() + dr.unsigned_abs() + (dq + dr).unsigned_abs()) / 2
+    }
+
+    /// Direction of `other` when it is adjacent.
+    pub fn direction_to(self, other: Hex) -> Option<HexDirection> {
+        HexDirection::ALL
+            .iter()
+            .copied()
+            .find(|direction| self.neighbor(*direction) == Some(other))
+    }
+
+    /// Hexes exactly `radius` steps away, starting from the one `radius`
+    /// steps to the east and going counterclockwise. Radius 0 is the hex
+    /// itself.
+    pub fn ring(self, radius: u32) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![self];
+        }
+
+        let radius = radius as i64;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut q = self.q as i64 + radius;
+        let mut r = self.r as i64;
+        // From the east corner, the first edge runs north-west
+        for direction in HexDirection::ALL.iter().map(|d| d.rotate_left(2)) {
+            let (dq, dr) = direction.offset();
+            for _ in 0..radius {
+                if let (Ok(hq), Ok(hr)) = (i32::try_from(q), i32::try_from(r)) {
+                    ring.push(Hex::new(hq, hr));
+                }
+                q += dq as i64;
+                r += dr as i64;
+            }
+        }
+        ring
+    }
+
+    /// Hexes on the straight line to `other`, both ends included, each one
+    /// step from the previous.
+    pub fn line_to(self, other: Hex) -> Vec<Hex> {
+        let steps = self.distance(other);
+        if steps == 0 {
+            return vec![self];
+        }
+
+        // Nudge off exact edges so ties round the same way in both directions
+        let (aq, ar) = (self.q as f64 + 1e-6, self.r as f64 + 1e-6);
+        let (bq, br) = (other.q as f64 + 1e-6, other.r as f64 + 1e-6);
+        (0..=steps)
+            .map(|i| {
+                let t = i as f64 / steps as f64;
+                CubeHex::round(aq + (bq - aq) * t, ar + (br - ar) * t).into()
+            })
+            .collect()
+    }
+
+    /// Whether both coordinates lie in `min..=max`.
+    pub fn is_within(self, min: i32, max: i32) -> bool {
+        (min..=max).contains(&self.q) && (min..=max).contains(&self.r)
+    }
+}
+
+impl From<(i32, i32)> for Hex {
+    fn from((q, r): (i32, i32)) -> Self {
+        Hex::new(q, r)
+    }
+}
+
+impl From<Hex> for (i32, i32) {
+    fn from(hex: Hex) -> Self {
+        (hex.q, hex.r)
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+impl CubeHex {
+    /// `None` unless `q + r + s == 0`.
+    pub fn new(q: i32, r: i32, s: i32) -> Option<Self> {
+        (q as i64 + r as i64 + s as i64 == 0).then_some(CubeHex { q, r, s })
+    }
+
+    pub fn distance(self, other: CubeHex) -> u64 {
+        Hex::from(self).distance(Hex::from(other))
+    }
+
+    /// Nearest hex to a fractional axial position.
+    fn round(q: f64, r: f64) -> CubeHex {
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        // Rounding each part alone can break q + r + s == 0; recompute the
+        // part that moved the most
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        let (q, r) = (rq as i32, rr as i32);
+        CubeHex { q, r, s: -q - r }
+    }
+}
+
+impl From<Hex> for CubeHex {
+    fn from(hex: Hex) -> Self {
+        // Only wraps far outside `MIN_COORDINATE..=MAX_COORDINATE`
+        CubeHex {
+            q: hex.q,
+            r: hex.r,
+            s: hex.q.wrapping_add(hex.r).wrapping_neg(),
+        }
+    }
+}
+
+impl From<CubeHex> for Hex {
+    fn from(cube: CubeHex) -> Self {
+        Hex::new(cube.q, cube.r)
+    }
+}
+
+impl HexDirection {
+    /// All directions, counterclockwise from east, indexed by discriminant.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    /// Axial `(q, r)` change of one step.
+    pub const fn offset(self) -> (i32, i32) {
+        match self {
+            HexDirection::East => (1, 0),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::West => (-1, 0),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::SouthEast => (0, 1),
+        }
+    }
+
+    pub fn from_index(index: u8) -> Option<Self> {
+        HexDirection::ALL.get(index as usize).copied()
+    }
+
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate_left(3)
+    }
+
+    /// Turns `sixths` of a full turn counterclockwise.
+    pub fn rotate_left(self, sixths: u8) -> Self {
+        HexDirection::ALL[(self as usize + sixths as usize) % 6]
+    }
+
+    /// Turns `sixths` of a full turn clockwise.
+    pub fn rotate_right(self, sixths: u8) -> Self {
+        self.rotate_left(6 - sixths % 6)
+    }
+
+    /// Compass abbreviation, as accepted by `from_str`.
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            HexDirection::East => "E",
+            HexDirection::NorthEast => "NE",
+            HexDirection::NorthWest => "NW",
+            HexDirection::West => "W",
+            HexDirection::SouthWest => "SW",
+            HexDirection::SouthEast => "SE",
+        }
+    }
+}
+
+impl TryFrom<u8> for HexDirection {
+    type Error = Error;
+
+    fn try_from(index: u8) -> Result<Self> {
+        Ok(HexDirection::from_index(index).ok_or(TidesError::InvalidDirection)?)
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = Error;
+
+    /// Parses an index (`"0"`-`"5"`), an abbreviation (`"NE"`) or a name
+    /// (`"north-east"`, `"NorthEast"`), ignoring case.
+    fn from_str(s: &str) -> Result<Self> {
+        let name: String = s
+            .trim()
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let direction = match name.as_str() {
+            "0" | "e" | "east" => HexDirection::East,
+            "1" | "ne" | "northeast" => HexDirection::NorthEast,
+            "2" | "nw" | "northwest" => HexDirection::NorthWest,
+            "3" | "w" | "west" => HexDirection::West,
+            "4" | "sw" | "southwest" => HexDirection::SouthWest,
+            "5" | "se" | "southeast" => HexDirection::SouthEast,
+            _ => return err!(TidesError::InvalidDirection),
+        };
+        Ok(direction)
+    }
+}
+
+impl fmt::Display for HexDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+// ============ Hex Module Functions ============
+
+pub mod hex {
+    use super::*;
+
+    /// Decodes direction bytes as sent in `move_player`.
+    pub fn parse_directions(directions: &[u8]) -> Result<Vec<HexDirection>> {
+        directions.iter().map(|d| HexDirection::try_from(*d)).collect()
+    }
+
+    /// Hex reached by following `directions` from `start`.
+    pub fn walk(start: Hex, directions: &[u8]) -> Result<Hex> {
+        parse_directions(directions)?
+            .into_iter()
+            .try_fold(start, |hex, direction| hex.step(direction))
+    }
+
+    /// Direction bytes for a path of adjacent hexes, the inverse of `walk`.
+    pub fn encode_path(path: &[Hex]) -> Result<Vec<u8>> {
+        path.windows(2)
+            .map(|pair| {
+                let direction = pair[0]
+                    .direction_to(pair[1])
+                    .ok_or(TidesError::InvalidDirection)?;
+                Ok(direction.index())
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::collections::HashSet;
+
+        fn h(q: i32, r: i32) -> Hex {
+            Hex::new(q, r)
+        }
+
+        // Every hex within `radius` of the origin
+        fn area(radius: i32) -> Vec<Hex> {
+            let mut hexes = Vec::new();
+            for q in -radius..=radius {
+                for r in -radius..=radius {
+                    if (q + r).abs() <= radius {
+                        hexes.push(h(q, r));
+                    }
+                }
+            }
+            hexes
+        }
+
+        #[test]
+        fn direction_offsets_match_legacy_tables() {
+            // Encoding used by clients before the hex module
+            let xs = [1, 1, 0, -1, -1, 0];
+            let ys = [0, -1, -1, 0, 1, 1];
+            for (i, direction) in HexDirection::ALL.iter().enumerate() {
+                assert_eq!(direction.offset(), (xs[i], ys[i]));
+                assert_eq!(direction.index() as usize, i);
+                assert_eq!(HexDirection::from_index(i as u8), Some(*direction));
+            }
+            assert_eq!(HexDirection::from_index(6), None);
+            assert!(HexDirection::try_from(255).is_err());
+        }
+
+        #[test]
+        fn direction_rotation_and_opposites() {
+            for direction in HexDirection::ALL {
+                let (dq, dr) = direction.offset();
+                assert_eq!(direction.opposite().offset(), (-dq, -dr));
+                assert_eq!(direction.opposite().opposite(), direction);
+                assert_eq!(direction.rotate_left(6), direction);
+                assert_eq!(direction.rotate_right(6), direction);
+                for sixths in 0..12 {
+                    assert_eq!(direction.rotate_left(sixths).rotate_right(sixths), direction);
+                }
+            }
+            assert_eq!(HexDirection::East.rotate_left(1), HexDirection::NorthEast);
+            assert_eq!(HexDirection::East.rotate_right(1), HexDirection::SouthEast);
+        }
+
+        #[test]
+        fn parses_directions() {
+            for direction in HexDirection::ALL {
+                assert_eq!(direction.abbreviation().parse::<HexDirection>().unwrap(), direction);
+                assert_eq!(direction.to_string().to_lowercase().parse::<HexDirection>().unwrap(), direction);
+                assert_eq!(direction.index().to_string().parse::<HexDirection>().unwrap(), direction);
+                assert_eq!(format!("{:?}", direction).parse::<HexDirection>().unwrap(), direction);
+            }
+            assert_eq!("north-east".parse::<HexDirection>().unwrap(), HexDirection::NorthEast);
+            assert_eq!(" South_West ".parse::<HexDirection>().unwrap(), HexDirection::SouthWest);
+            for bad in ["", "6", "N", "S", "up", "east!"] {
+                assert!(bad.parse::<HexDirection>().is_err(), "{bad:?} parsed");
+            }
+        }
+
+        #[test]
+        fn cube_coordinates() {
+            for hex in area(4) {
+                let cube = hex.to_cube();
+                assert_eq!(cube.q + cube.r + cube.s, 0);
+                assert_eq!(Hex::from(cube), hex);
+            }
+            assert_eq!(CubeHex::new(1, -3, 2), Some(CubeHex { q: 1, r: -3, s: 2 }));
+            assert_eq!(CubeHex::new(1, 1, 1), None);
+            assert_eq!(
+                CubeHex::new(2, -1, -1).unwrap().distance(CubeHex::new(-1, 2, -1).unwrap()),
+                3
+            );
+        }
+
+        #[test]
+        fn neighbors_are_one_step_away() {
+            for hex in area(3) {
+                let neighbors = hex.neighbors();
+                assert_eq!(neighbors.len(), 6);
+                assert_eq!(neighbors.iter().collect::<HashSet<_>>().len(), 6);
+                for (direction, neighbor) in HexDirection::ALL.iter().zip(&neighbors) {
+                    assert_eq!(hex.distance(*neighbor), 1);
+                    assert_eq!(hex.direction_to(*neighbor), Some(*direction));
+                    assert_eq!(neighbor.neighbor(direction.opposite()), Some(hex));
+                }
+            }
+            assert_eq!(h(0, 0).direction_to(h(2, 0)), None);
+            assert_eq!(h(0, 0).direction_to(h(0, 0)), None);
+        }
+
+        #[test]
+        fn neighbors_stop_at_i32_range() {
+            let corner = h(i32::MAX, i32::MIN);
+            assert_eq!(corner.neighbor(HexDirection::East), None);
+            assert_eq!(corner.neighbor(HexDirection::NorthWest), None);
+            assert_eq!(corner.neighbor(HexDirection::SouthEast), Some(h(i32::MAX, i32::MIN + 1)));
+            assert_eq!(corner.neighbors().len(), 3);
+            assert!(corner.step(HexDirection::NorthEast).is_err());
+        }
+
+        #[test]
+        fn distance_is_a_metric() {
+            let hexes = area(3);
+            for a in &hexes {
+                assert_eq!(a.distance(*a), 0);
+                for b in &hexes {
+                    let d = a.distance(*b);
+                    assert_eq!(d, b.distance(*a));
+                    assert_eq!(d == 0, a == b);
+                    let (dq, dr, ds) = (b.q - a.q, b.r - a.r, (b.to_cube().s - a.to_cube().s));
+                    assert_eq!(d, [dq.abs(), dr.abs(), ds.abs()].into_iter().max().unwrap() as u64);
+                    for c in &hexes {
+                        assert!(a.distance(*c) <= d + b.distance(*c));
+                    }
+                }
+            }
+            assert_eq!(h(i32::MIN, 0).distance(h(i32::MAX, 0)), u32::MAX as u64);
+        }
+
+        #[test]
+        fn rings_hold_every_hex_at_radius() {
+            let center = h(-2, 5);
+            assert_eq!(center.ring(0), vec![center]);
+            for radius in 1..=5u32 {
+                let ring = center.ring(radius);
+                assert_eq!(ring.len(), 6 * radius as usize);
+                assert_eq!(ring[0], h(center.q + radius as i32, center.r));
+                assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+                for (i, hex) in ring.iter().enumerate() {
+                    assert_eq!(center.distance(*hex), radius as u64);
+                    assert_eq!(hex.distance(ring[(i + 1) % ring.len()]), 1);
+                }
+                let expected = area(radius as i32)
+                    .into_iter()
+                    .filter(|hex| Hex::ORIGIN.distance(*hex) == radius as u64)
+                    .count();
+                assert_eq!(ring.len(), expected);
+            }
+        }
+
+        #[test]
+        fn lines_step_one_hex_at_a_time() {
+            let hexes = area(4);
+            for a in &hexes {
+                for b in &hexes {
+                    let line = a.line_to(*b);
+                    assert_eq!(line.len() as u64, a.distance(*b) + 1);
+                    assert_eq!(line.first(), Some(a));
+                    assert_eq!(line.last(), Some(b));
+                    for pair in line.windows(2) {
+                        assert_eq!(pair[0].distance(pair[1]), 1);
+                    }
+                }
+            }
+            assert_eq!(h(0, 0).line_to(h(3, 0)), vec![h(0, 0), h(1, 0), h(2, 0), h(3, 0)]);
+            assert_eq!(h(1, 1).line_to(h(1, 1)), vec![h(1, 1)]);
+        }
+
+        #[test]
+        fn walks_and_encodes_paths() {
+            let start = h(3, -7);
+            let directions = [0, 1, 2, 3, 4, 5, 5, 0];
+            let end = walk(start, &directions).unwrap();
+            assert_eq!(end, h(4, -6));
+
+            let mut path = vec![start];
+            for direction in parse_directions(&directions).unwrap() {
+                path.push(path.last().unwrap().step(direction).unwrap());
+            }
+            assert_eq!(*path.last().unwrap(), end);
+            assert_eq!(encode_path(&path).unwrap(), directions.to_vec());
+
+            assert_eq!(walk(start, &[]).unwrap(), start);
+            assert!(walk(start, &[0, 6]).is_err());
+            assert!(walk(h(i32::MAX, 0), &[0]).is_err());
+            assert!(encode_path(&[h(0, 0), h(2, 0)]).is_err());
+            assert_eq!(encode_path(&[start]).unwrap(), Vec::<u8>::new());
+        }
+
+        #[test]
+        fn bounds() {
+            assert!(h(0, 0).is_within(-1, 1));
+            assert!(h(-1, 1).is_within(-1, 1));
+            assert!(!h(2, 0).is_within(-1, 1));
+            assert!(!h(0, -2).is_within(-1, 1));
+        }
+
+        #[test]
+        fn serializes_with_borsh_and_serde() {
+            let hex = h(-12, 34);
+            let bytes = hex.try_to_vec().unwrap();
+            assert_eq!(bytes, [(-12i32).to_le_bytes(), 34i32.to_le_bytes()].concat());
+            assert_eq!(Hex::try_from_slice(&bytes).unwrap(), hex);
+
+            let cube = hex.to_cube();
+            assert_eq!(CubeHex::try_from_slice(&cube.try_to_vec().unwrap()).unwrap(), cube);
+
+            for direction in HexDirection::ALL {
+                assert_eq!(direction.try_to_vec().unwrap(), vec![direction.index()]);
+                assert_eq!(HexDirection::try_from_slice(&[direction.index()]).unwrap(), direction);
+            }
+            assert!(HexDirection::try_from_slice(&[6]).is_err());
+
+            let json = serde_json::to_string(&hex).unwrap();
+            assert_eq!(json, r#"{"q":-12,"r":34}"#);
+            assert_eq!(serde_json::from_str::<Hex>(&json).unwrap(), hex);
+            let json = serde_json::to_string(&HexDirection::NorthWest).unwrap();
+            assert_eq!(serde_json::from_str::<HexDirection>(&json).unwrap(), HexDirection::NorthWest);
+            assert_eq!(Hex::from((5, -5)), h(5, -5));
+            assert_eq!(<(i32, i32)>::from(h(5, -5)), (5, -5));
+            assert_eq!(h(5, -5).to_string(), "(5, -5)");
+        }
+    }
+}
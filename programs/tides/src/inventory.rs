@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{TidesError, SlotType, ItemType};
+use crate::{TidesError, SlotType, ItemType, ACCOUNT_RESERVED_BYTES};
 
 // ============ Inventory Account Structures ============
 
@@ -15,6 +15,8 @@ pub struct PlayerInventory {
     // Items stored as a flat array for now
     // In production, might want a more efficient structure
     pub items: Vec<GridItem>,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl PlayerInventory {
@@ -26,7 +28,9 @@ impl PlayerInventory {
         1 + // height
         8 + // next_instance_id
         4 + (width as usize * height as usize) + // slot_types Vec
-        4 + (width as usize * height as usize * std::mem::size_of::<GridItem>()) // items Vec
+        4 + (width as usize * height as usize * std::mem::size_of::<GridItem>()) + // items Vec
+        1 + // version
+        ACCOUNT_RESERVED_BYTES // _reserved
     }
 }
 
@@ -56,6 +60,8 @@ pub struct FishCatch {
     pub species: u64,
     pub weight: u16,
     pub caught_timestamp: i64,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl FishCatch {
@@ -64,7 +70,9 @@ impl FishCatch {
         8 + // instance_id
         8 + // species
         2 + // weight
-        8; // caught_timestamp
+        8 + // caught_timestamp
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
 }
 
 // ============ Inventory Module ============
@@ -182,6 +190,53 @@ pub mod inventory {
         Ok(())
     }
 
+    /// Moves the item at (`from_x`, `from_y`) to (`to_x`, `to_y`) with
+    /// `rotation`. Returns the item as it was before the move.
+    pub fn move_item(
+        inventory: &mut Account<PlayerInventory>,
+        from_x: u8,
+        from_y: u8,
+        to_x: u8,
+        to_y: u8,
+        rotation: u8,
+    ) -> Result<GridItem> {
+        let item = get_item_at(inventory, from_x, from_y)?;
+        require!(item.item_type != ItemType::Empty, TidesError::ItemNotFound);
+
+        // Remove from old position
+        remove_item(inventory, item.instance_id)?;
+
+        // Place at new position (simplified - would get shape from registry)
+        place_item(
+            inventory,
+            item.item_type,
+            item.item_id,
+            to_x,
+            to_y,
+            rotation,
+            1, // shape_width (simplified)
+            1, // shape_height (simplified)
+            vec![], // shape_data (simplified)
+        )?;
+
+        Ok(item)
+    }
+
+    /// Removes the item at (`x`, `y`). Fish also need their `FishCatch`
+    /// closed by the caller.
+    pub fn discard_item(
+        inventory: &mut Account<PlayerInventory>,
+        x: u8,
+        y: u8,
+    ) -> Result<GridItem> {
+        let item = get_item_at(inventory, x, y)?;
+        require!(item.item_type != ItemType::Empty, TidesError::ItemNotFound);
+
+        remove_item(inventory, item.instance_id)?;
+
+        Ok(item)
+    }
+
     pub fn get_item_at(
         inventory: &Account<PlayerInventory>,
         x: u8,
@@ -193,6 +248,16 @@ pub mod inventory {
         Ok(inventory.items[idx as usize])
     }
 
+    pub fn contains_instance(
+        inventory: &Account<PlayerInventory>,
+        instance_id: u64,
+    ) -> bool {
+        inventory
+            .items
+            .iter()
+            .any(|item| item.item_type != ItemType::Empty && item.instance_id == instance_id)
+    }
+
     pub fn has_equipped_item_type(
         inventory: &Account<PlayerInventory>,
         item_type: ItemType,
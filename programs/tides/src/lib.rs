@@ -1,37 +1,87 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Approve, Mint, Revoke, Token, TokenAccount, Transfer};
 
 // Import modules
+pub mod access_control;
+pub mod actions;
+pub mod currents;
 pub mod fishing;
+pub mod governance;
+pub mod hex;
 pub mod inventory;
+pub mod migrations;
+#[cfg(feature = "pathfinding")]
+pub mod pathfinding;
 pub mod registries;
 pub mod season_pass;
+pub mod session;
 
 // Re-export for convenience
+pub use access_control::*;
+pub use actions::*;
+pub use currents::*;
 pub use fishing::*;
+pub use governance::*;
+pub use hex::*;
 pub use inventory::*;
+pub use migrations::*;
+#[cfg(feature = "pathfinding")]
+pub use pathfinding::*;
 pub use registries::*;
 pub use season_pass::*;
+pub use session::*;
 
 declare_id!("Tides1111111111111111111111111111111111111111");
 
 // ============ Constants ============
 pub const FUEL_PRICE_PER_UNIT: u64 = 10_000_000_000; // 10 TTC (tides token) per fuel unit (with 9 decimals)
 pub const MAX_SHARDS: u8 = 100;
+pub const SHARD_DIRECTORY_SIZE: usize = MAX_SHARDS as usize;
 pub const HEX_MOVE_COST: u64 = 1_000_000_000; // Base fuel cost per hex (1 TTC)
+pub const MAX_MOVE_DIRECTIONS: usize = 20; // Hexes per move_player call
 pub const BASE_MOVEMENT_SPEED: u64 = 1000; // Base movement speed (lower = faster)
 pub const PRICE_DECAY_RATE: u64 = 5; // 5% decrease per fish sale
 pub const PRICE_RECOVERY_RATE: u64 = 463; // ~100% in 6 hours
 pub const FRESHNESS_DECAY_PERIOD: i64 = 900; // 15 minutes in seconds
 pub const FRESHNESS_DECAY_RATE: u64 = 25; // 25%
+pub const SHARD_CHANGE_COOLDOWN: i64 = 86_400; // 24 hours between self-service shard changes
+pub const STARTING_SHIP_ID: u64 = 1; // Ship given to every new player
+
+// Account layout versioning. Every account ends with a version byte and
+// reserved bytes; new fields take reserved space and bump the version.
+// Accounts created before versioning have neither and are upgraded by the
+// `migrate_*` instructions.
+// 1: version byte and reserved space
+// 2: player and registry accounts store their `game_state`
+// 3: maps store their current field
+// 4: pending changes store when they expire
+// 5: the season pass state stores its last ended season
+pub const ACCOUNT_VERSION: u8 = 5;
+pub const ACCOUNT_RESERVED_BYTES: usize = 64;
+
+// Pause flags, one bit per subsystem in `GameState.paused`
+pub const PAUSE_MOVEMENT: u8 = 1 << 0;
+pub const PAUSE_FISHING: u8 = 1 << 1;
+pub const PAUSE_MARKET: u8 = 1 << 2;
+pub const PAUSE_SHOP: u8 = 1 << 3;
+pub const PAUSE_INVENTORY: u8 = 1 << 4;
+pub const PAUSE_SEASONS: u8 = 1 << 5;
+pub const PAUSE_REGISTRATION: u8 = 1 << 6;
+pub const PAUSE_ALL: u8 = PAUSE_MOVEMENT
+    | PAUSE_FISHING
+    | PAUSE_MARKET
+    | PAUSE_SHOP
+    | PAUSE_INVENTORY
+    | PAUSE_SEASONS
+    | PAUSE_REGISTRATION;
 
 // Movement constraints
 pub const MAX_COORDINATE: i32 = 1000;
 pub const MIN_COORDINATE: i32 = -1000;
 
-// Hex movement directions (0=NE, 1=E, 2=SE, 3=SW, 4=W, 5=NW)
-pub const HEX_DIRECTIONS_X: [i32; 6] = [1, 1, 0, -1, -1, 0];
-pub const HEX_DIRECTIONS_Y: [i32; 6] = [0, -1, -1, 0, 1, 1];
 
 // ============ Enums ============
 
@@ -57,9 +107,40 @@ pub enum ItemType {
 pub struct GameState {
     pub currency_mint: Pubkey,
     pub admin: Pubkey,
-    pub paused: bool,
+    pub paused: u8, // PAUSE_* flags of the subsystems currently paused
     pub max_players_per_shard: u64,
     pub server_signer: Pubkey,
+    pub shard_change_fee: u64,
+    pub pending_admin: Pubkey, // Proposed admin, default when none
+    pub timelock_delay: i64, // Seconds before a proposed change can execute
+    pub next_change_id: u64,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
+}
+
+impl GameState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // currency_mint
+        32 + // admin
+        1 + // paused
+        8 + // max_players_per_shard
+        32 + // server_signer
+        8 + // shard_change_fee
+        32 + // pending_admin
+        8 + // timelock_delay
+        8 + // next_change_id
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+
+    /// Address of the game state PDA, the only `GameState` the program accepts.
+    pub fn address() -> Pubkey {
+        Pubkey::find_program_address(&[b"game"], &crate::ID).0
+    }
+
+    pub fn require_unpaused(&self, flag: u8) -> Result<()> {
+        require!(self.paused & flag == 0, TidesError::GamePaused);
+        Ok(())
+    }
 }
 
 #[account]
@@ -75,6 +156,10 @@ pub struct PlayerState {
     pub position_y: i32,
     pub shard: u8,
     pub is_active: bool,
+    pub last_shard_change: i64,
+    pub version: u8,
+    pub game_state: Pubkey, // GameState this account belongs to
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 32],
 }
 
 impl PlayerState {
@@ -89,7 +174,61 @@ impl PlayerState {
         4 + // position_x
         4 + // position_y
         1 + // shard
-        1; // is_active
+        1 + // is_active
+        8 + // last_shard_change
+        1 + // version
+        32 + // game_state
+        ACCOUNT_RESERVED_BYTES - 32; // _reserved
+
+    pub fn position(&self) -> Hex {
+        Hex::new(self.position_x, self.position_y)
+    }
+
+    /// Sails along `directions` (`HexDirection` indices) on `map`, burning
+    /// fuel as priced by `currents::route_cost` and starting the move
+    /// cooldown. Returns the fuel consumed.
+    pub fn sail(&mut self, map: &registries::Map, directions: &[u8], now: i64) -> Result<u64> {
+        require!(map.map_id == self.map_id, TidesError::InvalidMap);
+        require!(now >= self.next_move_time, TidesError::OnCooldown);
+        require!(!directions.is_empty(), TidesError::NoDirectionsProvided);
+        require!(directions.len() <= MAX_MOVE_DIRECTIONS, TidesError::TooManyMoves);
+
+        let fuel_cost = currents::currents::route_cost(map, self.position(), directions, now)?;
+        require!(self.current_fuel >= fuel_cost, TidesError::InsufficientFuel);
+
+        // Every hex sailed through must be on the map, not just the last one
+        let mut destination = self.position();
+        for heading in hex::hex::parse_directions(directions)? {
+            destination = destination.step(heading)?;
+            require!(
+                destination.is_within(MIN_COORDINATE, MAX_COORDINATE)
+                    && registries::registries::is_valid_position(map, destination),
+                TidesError::PositionOutOfBounds
+            );
+        }
+
+        self.position_x = destination.q;
+        self.position_y = destination.r;
+        self.current_fuel = self
+            .current_fuel
+            .checked_sub(fuel_cost)
+            .ok_or(TidesError::MathOverflow)?;
+        self.last_move_timestamp = now;
+        self.next_move_time = now
+            .checked_add((self.movement_speed * directions.len() as u64) as i64)
+            .ok_or(TidesError::MathOverflow)?;
+
+        Ok(fuel_cost)
+    }
+
+    /// Adds `amount` purchased fuel units to the tank.
+    pub fn add_fuel(&mut self, amount: u64) -> Result<()> {
+        self.current_fuel = self
+            .current_fuel
+            .checked_add(amount.checked_mul(1_000_000_000).ok_or(TidesError::MathOverflow)?)
+            .ok_or(TidesError::MathOverflow)?;
+        Ok(())
+    }
 }
 
 #[account]
@@ -97,25 +236,125 @@ pub struct FishMarketData {
     pub species: u64,
     pub value: u64,
     pub last_sold_timestamp: i64,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl FishMarketData {
     pub const LEN: usize = 8 + // discriminator
         8 + // species
         8 + // value
-        8; // last_sold_timestamp
+        8 + // last_sold_timestamp
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
 }
 
 #[account]
 pub struct ShardData {
     pub shard_id: u8,
     pub player_count: u64,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl ShardData {
     pub const LEN: usize = 8 + // discriminator
         1 + // shard_id
-        8; // player_count
+        8 + // player_count
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+
+    /// Address of the PDA of shard `shard_id`.
+    pub fn address(shard_id: u8) -> Pubkey {
+        Pubkey::find_program_address(&[b"shard", &[shard_id]], &crate::ID).0
+    }
+
+    /// Adds a player to the shard, checking capacity before the count changes.
+    pub fn add_player(&mut self, max_players: u64) -> Result<()> {
+        require!(self.player_count < max_players, TidesError::ShardFull);
+        self.player_count = self.player_count.checked_add(1).ok_or(TidesError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Removes a player from the shard (on shard change or deregistration).
+    pub fn remove_player(&mut self) -> Result<()> {
+        self.player_count = self.player_count.checked_sub(1).ok_or(TidesError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Mirrors `ShardData.player_count` for every shard so that shard selection
+/// can be done without loading each `ShardData` account.
+#[account]
+pub struct ShardDirectory {
+    pub player_counts: [u64; SHARD_DIRECTORY_SIZE],
+    pub is_open: [bool; SHARD_DIRECTORY_SIZE],
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
+}
+
+impl ShardDirectory {
+    pub const LEN: usize = 8 + // discriminator
+        8 * SHARD_DIRECTORY_SIZE + // player_counts
+        SHARD_DIRECTORY_SIZE + // is_open
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+
+    /// Copies the current player count of `shard_data` into the directory.
+    pub fn sync(&mut self, shard_data: &ShardData) {
+        self.player_counts[shard_data.shard_id as usize] = shard_data.player_count;
+    }
+
+    /// Returns the open shard with the fewest players that still has room,
+    /// preferring the lowest shard id on ties.
+    pub fn least_populated_open_shard(&self, max_players: u64) -> Option<u8> {
+        (0..MAX_SHARDS)
+            .filter(|&shard| {
+                self.is_open[shard as usize] && self.player_counts[shard as usize] < max_players
+            })
+            .min_by_key(|&shard| self.player_counts[shard as usize])
+    }
+
+    /// Returns the shard a player in `current` should move to, if moving them
+    /// would narrow the gap between the two shards.
+    pub fn rebalance_target(&self, current: u8, max_players: u64) -> Option<u8> {
+        let target = self.least_populated_open_shard(max_players)?;
+        let target_count = self.player_counts[target as usize];
+        let current_count = self.player_counts[current as usize];
+        if target != current && target_count.saturating_add(1) < current_count {
+            Some(target)
+        } else {
+            None
+        }
+    }
+
+    /// Moves a player between shards, keeping both `ShardData` accounts and
+    /// the directory in step. Shared by the admin shard change and rebalancing.
+    pub fn transfer_player(
+        &mut self,
+        player_state: &mut PlayerState,
+        old_shard_data: &mut ShardData,
+        new_shard_data: &mut ShardData,
+        max_players: u64,
+    ) -> Result<()> {
+        require!(
+            old_shard_data.shard_id == player_state.shard,
+            TidesError::InvalidShardId
+        );
+        require!(
+            new_shard_data.shard_id != player_state.shard,
+            TidesError::AlreadyInShard
+        );
+
+        new_shard_data.add_player(max_players)?;
+        old_shard_data.remove_player()?;
+        self.sync(old_shard_data);
+        self.sync(new_shard_data);
+
+        player_state.shard = new_shard_data.shard_id;
+
+        Ok(())
+    }
 }
 
 // ============ Program Module ============
@@ -133,26 +372,52 @@ pub mod tides {
         let game_state = &mut ctx.accounts.game_state;
         game_state.currency_mint = currency_mint;
         game_state.admin = ctx.accounts.admin.key();
-        game_state.paused = false;
+        game_state.paused = 0;
         game_state.max_players_per_shard = max_players_per_shard;
         game_state.server_signer = server_signer;
-        Ok(())
+        game_state.shard_change_fee = 0;
+        game_state.pending_admin = Pubkey::default();
+        game_state.timelock_delay = DEFAULT_TIMELOCK_DELAY;
+        game_state.next_change_id = 0;
+        game_state.version = ACCOUNT_VERSION;
+
+        init_game_accounts(
+            &mut ctx.accounts.shard_directory,
+            &mut ctx.accounts.access_control,
+            &mut ctx.accounts.oracle_set,
+            game_state.admin,
+            server_signer,
+        )
     }
 
+    /// Registers a player into `shard`, or into the least-populated open
+    /// shard when `shard` is `None`. In auto mode the client must pass the
+    /// `ShardData` account of the shard the directory will select.
     pub fn register_player(
         ctx: Context<RegisterPlayer>,
-        shard: u8,
+        shard: Option<u8>,
         map_id: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
-        require!(shard < MAX_SHARDS, TidesError::InvalidShardId);
+        ctx.accounts.game_state.require_unpaused(PAUSE_REGISTRATION)?;
         require!(!ctx.accounts.player_state.is_active, TidesError::PlayerAlreadyRegistered);
 
+        let max_players = ctx.accounts.game_state.max_players_per_shard;
+        let shard = match shard {
+            Some(shard) => shard,
+            None => ctx
+                .accounts
+                .shard_directory
+                .least_populated_open_shard(max_players)
+                .ok_or(TidesError::NoOpenShard)?,
+        };
+        require!(shard < MAX_SHARDS, TidesError::InvalidShardId);
+        require!(ctx.accounts.shard_data.shard_id == shard, TidesError::InvalidShardId);
+
         // Initialize player state
         let player_state = &mut ctx.accounts.player_state;
         player_state.player = ctx.accounts.player.key();
         player_state.map_id = map_id;
-        player_state.ship_id = 1; // Default ship
+        player_state.ship_id = STARTING_SHIP_ID;
         player_state.current_fuel = 100_000_000_000; // 100 fuel units
         player_state.last_move_timestamp = Clock::get()?.unix_timestamp;
         player_state.next_move_time = Clock::get()?.unix_timestamp;
@@ -161,16 +426,28 @@ pub mod tides {
         player_state.position_y = 0;
         player_state.shard = shard;
         player_state.is_active = true;
+        player_state.last_shard_change = 0;
+        player_state.version = ACCOUNT_VERSION;
+        player_state.game_state = ctx.accounts.game_state.key();
 
-        // Update shard count
-        let shard_data = &mut ctx.accounts.shard_data;
-        shard_data.shard_id = shard;
-        shard_data.player_count = shard_data.player_count.checked_add(1).ok_or(TidesError::MathOverflow)?;
+        let fishing_state = &mut ctx.accounts.fishing_state;
+        fishing_state.player = ctx.accounts.player.key();
+        fishing_state.version = ACCOUNT_VERSION;
 
-        require!(
-            shard_data.player_count <= ctx.accounts.game_state.max_players_per_shard,
-            TidesError::ShardFull
-        );
+        let ship = &ctx.accounts.ship;
+        inventory::initialize_inventory(
+            &mut ctx.accounts.player_inventory,
+            &ctx.accounts.player.key(),
+            ship.ship_id,
+            ship.cargo_width,
+            ship.cargo_height,
+            ship.slot_types.clone(),
+        )?;
+        ctx.accounts.player_inventory.version = ACCOUNT_VERSION;
+
+        // Update shard count
+        ctx.accounts.shard_data.add_player(max_players)?;
+        ctx.accounts.shard_directory.sync(&ctx.accounts.shard_data);
 
         emit!(PlayerRegistered {
             player: ctx.accounts.player.key(),
@@ -180,63 +457,102 @@ pub mod tides {
         Ok(())
     }
 
-    pub fn move_player(
-        ctx: Context<MovePlayer>,
-        directions: Vec<u8>,
+    /// Removes the player from the game and returns the rent of their
+    /// accounts. `remaining_accounts` holds `bait_account_count` `PlayerBait`
+    /// accounts followed by any `FishCatch` accounts to close.
+    pub fn deregister_player<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeregisterPlayer<'info>>,
+        bait_account_count: u8,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_REGISTRATION)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
-        
-        let clock = Clock::get()?;
-        let player_state = &mut ctx.accounts.player_state;
-        
         require!(
-            clock.unix_timestamp >= player_state.next_move_time,
-            TidesError::OnCooldown
+            bait_account_count as usize <= ctx.remaining_accounts.len(),
+            TidesError::ArrayLengthMismatch
         );
-        
-        require!(!directions.is_empty(), TidesError::NoDirectionsProvided);
-        require!(directions.len() <= 20, TidesError::TooManyMoves);
 
-        // Calculate fuel cost
-        let fuel_cost = calculate_fuel_cost(directions.len() as u64);
         require!(
-            player_state.current_fuel >= fuel_cost,
-            TidesError::InsufficientFuel
+            ctx.accounts.fishing_state.pending_nonce == 0,
+            TidesError::PendingFishingRequest
         );
 
-        // Move player (simplified - would need map validation in full implementation)
-        let mut new_x = player_state.position_x;
-        let mut new_y = player_state.position_y;
+        // Neither the current season nor the last ended one, whose rewards
+        // may still be unclaimed, can lose a player whose stats they rank
+        let now = Clock::get()?.unix_timestamp;
+        season_pass::require_unlocked(&ctx.accounts.player_stats, ctx.accounts.season.as_ref(), now)?;
+        season_pass::require_unlocked(
+            &ctx.accounts.ended_season_stats,
+            ctx.accounts.ended_season.as_ref(),
+            now,
+        )?;
+
+        let player = ctx.accounts.player.key();
+        let player_info = ctx.accounts.player.to_account_info();
+        let (bait_infos, catch_infos) = ctx
+            .remaining_accounts
+            .split_at(bait_account_count as usize);
 
-        for direction in &directions {
-            require!(*direction < 6, TidesError::InvalidDirection);
-            new_x = new_x
-                .checked_add(HEX_DIRECTIONS_X[*direction as usize])
-                .ok_or(TidesError::MathOverflow)?;
-            new_y = new_y
-                .checked_add(HEX_DIRECTIONS_Y[*direction as usize])
-                .ok_or(TidesError::MathOverflow)?;
+        // Bait bought before purchases recorded the owner has no `player`,
+        // so the PDA is what ties it to the player
+        for bait_info in bait_infos {
+            let bait = Account::<fishing::PlayerBait>::try_from(bait_info)?;
+            require!(
+                bait_info.key() == fishing::PlayerBait::address(&player, bait.bait_type),
+                TidesError::InvalidPlayer
+            );
+            bait.close(player_info.clone())?;
         }
 
-        // Update position and fuel
-        player_state.position_x = new_x;
-        player_state.position_y = new_y;
-        player_state.current_fuel = player_state
-            .current_fuel
-            .checked_sub(fuel_cost)
-            .ok_or(TidesError::MathOverflow)?;
-        player_state.last_move_timestamp = clock.unix_timestamp;
-        player_state.next_move_time = clock.unix_timestamp
-            .checked_add((player_state.movement_speed * directions.len() as u64) as i64)
-            .ok_or(TidesError::MathOverflow)?;
+        for catch_info in catch_infos {
+            let fish_catch = Account::<inventory::FishCatch>::try_from(catch_info)?;
+            require!(fish_catch.player == player, TidesError::InvalidPlayer);
+            fish_catch.close(player_info.clone())?;
+        }
+
+        // Update shard count
+        ctx.accounts.shard_data.remove_player()?;
+        ctx.accounts.shard_directory.sync(&ctx.accounts.shard_data);
+
+        emit!(PlayerDeregistered {
+            player,
+            shard: ctx.accounts.player_state.shard,
+        });
+
+        Ok(())
+    }
+
+    pub fn move_player(
+        ctx: Context<MovePlayer>,
+        directions: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_MOVEMENT)?;
+        require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
+        
+        let clock = Clock::get()?;
+        session::authorize(
+            &ctx.accounts.player.key(),
+            &ctx.accounts.authority.key(),
+            ctx.accounts.session_token.as_ref(),
+            SESSION_MOVE,
+            clock.unix_timestamp,
+        )?;
+        let player_state = &mut ctx.accounts.player_state;
+        let fuel_cost = player_state.sail(&ctx.accounts.map, &directions, clock.unix_timestamp)?;
+
+        season_pass::award_xp(
+            ctx.accounts.season.as_ref(),
+            ctx.accounts.player_stats.as_deref_mut(),
+            ctx.accounts.season_track.as_ref(),
+            season_pass::XpSource::Sailing,
+            directions.len() as u64,
+        )?;
 
         emit!(PlayerMoved {
             player: ctx.accounts.player.key(),
             shard: player_state.shard,
             map_id: player_state.map_id,
-            x: new_x,
-            y: new_y,
+            x: player_state.position_x,
+            y: player_state.position_y,
             fuel_consumed: fuel_cost,
         });
 
@@ -247,30 +563,44 @@ pub mod tides {
         ctx: Context<PurchaseFuel>,
         amount: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_SHOP)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         require!(amount > 0, TidesError::InvalidAmount);
+        let via_session = session::authorize(
+            &ctx.accounts.player.key(),
+            &ctx.accounts.authority.key(),
+            ctx.accounts.session_token.as_ref(),
+            SESSION_PURCHASE_FUEL,
+            Clock::get()?.unix_timestamp,
+        )?;
 
         let total_cost = amount
             .checked_mul(FUEL_PRICE_PER_UNIT)
             .ok_or(TidesError::MathOverflow)?;
 
         // Transfer tokens from player to game (burn)
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.player_token_account.to_account_info(),
-            to: ctx.accounts.game_token_account.to_account_info(),
-            authority: ctx.accounts.player.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, total_cost)?;
+        session::spend(
+            via_session,
+            ctx.accounts.session_token.as_mut(),
+            &ctx.accounts.authority,
+            &ctx.accounts.player_token_account,
+            &ctx.accounts.game_token_account,
+            &ctx.accounts.token_program,
+            total_cost,
+        )?;
 
         // Add fuel to player
-        let player_state = &mut ctx.accounts.player_state;
-        player_state.current_fuel = player_state
-            .current_fuel
-            .checked_add(amount.checked_mul(1_000_000_000).ok_or(TidesError::MathOverflow)?)
-            .ok_or(TidesError::MathOverflow)?;
+        ctx.accounts.player_state.add_fuel(amount)?;
+
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        season_pass::record_activity(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.leaderboard.as_ref(),
+            0,
+            total_cost,
+        )?;
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
 
         emit!(FuelPurchased {
             player: ctx.accounts.player.key(),
@@ -281,19 +611,25 @@ pub mod tides {
         Ok(())
     }
 
+    /// Sells the fish with `instance_id` from the hold. Species, weight and
+    /// catch time come from its `FishCatch`, which is closed to the player.
     pub fn sell_fish(
         ctx: Context<SellFish>,
         instance_id: u64,
-        species: u64,
-        weight: u16,
-        caught_timestamp: i64,
     ) -> Result<u64> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_MARKET)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
+
+        let fish_catch = &ctx.accounts.fish_catch;
+        require!(fish_catch.instance_id == instance_id, TidesError::ItemNotFound);
+        let species = fish_catch.species;
+        let weight = fish_catch.weight;
         require!(species > 0, TidesError::InvalidSpecies);
 
+        inventory::remove_item(&mut ctx.accounts.player_inventory, instance_id)?;
+
         let clock = Clock::get()?;
-        let freshness = calculate_fish_freshness(caught_timestamp, clock.unix_timestamp);
+        let freshness = calculate_fish_freshness(fish_catch.caught_timestamp, clock.unix_timestamp);
         
         let sale_price = update_fish_market_data(
             &mut ctx.accounts.fish_market,
@@ -307,6 +643,23 @@ pub mod tides {
         // In full implementation, would call SPL token mint instruction
         // This is simplified for structure
 
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        season_pass::record_activity(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.leaderboard.as_ref(),
+            sale_price,
+            0,
+        )?;
+        season_pass::award_xp(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.season_track.as_ref(),
+            season_pass::XpSource::Sale,
+            1,
+        )?;
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
+
         emit!(FishSold {
             species,
             weight: weight as u64,
@@ -321,7 +674,7 @@ pub mod tides {
         ctx: Context<TravelToMap>,
         new_map_id: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_MOVEMENT)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         
         let player_state = &mut ctx.accounts.player_state;
@@ -350,6 +703,16 @@ pub mod tides {
         player_state.position_x = 0;
         player_state.position_y = 0;
         
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        season_pass::record_activity(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.leaderboard.as_ref(),
+            0,
+            travel_cost,
+        )?;
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
+
         emit!(MapChanged {
             player: ctx.accounts.player.key(),
             old_map_id,
@@ -364,7 +727,7 @@ pub mod tides {
         ctx: Context<ChangeShip>,
         new_ship_id: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_INVENTORY)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         
         let ship = &ctx.accounts.ship;
@@ -385,7 +748,7 @@ pub mod tides {
         ctx: Context<PurchaseShip>,
         ship_id: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_SHOP)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         
         // Check if player is at harbor (simplified - would check map registry)
@@ -407,6 +770,16 @@ pub mod tides {
         let player_state = &mut ctx.accounts.player_state;
         player_state.ship_id = ship_id;
         
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        season_pass::record_activity(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.leaderboard.as_ref(),
+            0,
+            cost,
+        )?;
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
+
         emit!(ShipPurchased {
             player: ctx.accounts.player.key(),
             ship_id,
@@ -425,7 +798,7 @@ pub mod tides {
         ctx: Context<PurchaseEngine>,
         engine_id: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_SHOP)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         
         let engine = &ctx.accounts.engine;
@@ -443,6 +816,16 @@ pub mod tides {
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         token::transfer(cpi_ctx, cost)?;
         
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        season_pass::record_activity(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.leaderboard.as_ref(),
+            0,
+            cost,
+        )?;
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
+
         emit!(EnginePurchased {
             player: ctx.accounts.player.key(),
             engine_id,
@@ -456,7 +839,7 @@ pub mod tides {
         ctx: Context<PurchaseFishingRod>,
         rod_id: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_SHOP)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         
         let rod = &ctx.accounts.fishing_rod;
@@ -474,6 +857,16 @@ pub mod tides {
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         token::transfer(cpi_ctx, cost)?;
         
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        season_pass::record_activity(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.leaderboard.as_ref(),
+            0,
+            cost,
+        )?;
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
+
         emit!(FishingRodPurchased {
             player: ctx.accounts.player.key(),
             rod_id,
@@ -487,8 +880,15 @@ pub mod tides {
         ctx: Context<InitiateFishing>,
         bait_type: u64,
     ) -> Result<u64> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_FISHING)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
+        session::authorize(
+            &ctx.accounts.player.key(),
+            &ctx.accounts.authority.key(),
+            ctx.accounts.session_token.as_ref(),
+            SESSION_FISHING,
+            Clock::get()?.unix_timestamp,
+        )?;
         
         // Check if fishing rod is equipped (simplified - would check inventory)
         let bait = &mut ctx.accounts.player_bait;
@@ -520,34 +920,59 @@ pub mod tides {
         Ok(fishing_nonce)
     }
 
+    /// Applies an oracle-signed fishing result. The transaction must carry
+    /// Ed25519 program instructions signing `fishing::fishing_result_message`
+    /// from enough keys in the oracle set: one, or `cosign_required` for
+    /// species whose base price reaches `cosign_min_base_price`. Catches
+    /// must pass their `fish_species` PDA from the game's fish registry.
     pub fn fulfill_fishing(
         ctx: Context<FulfillFishing>,
         species: u64,
         weight: u16,
         timestamp: i64,
-        signature: Vec<u8>,
         should_place: bool,
         place_x: u8,
         place_y: u8,
         rotation: u8,
     ) -> Result<u64> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_FISHING)?;
         
         let clock = Clock::get()?;
         let fishing_state = &mut ctx.accounts.fishing_state;
         
         require!(fishing_state.pending_nonce > 0, TidesError::ExpiredFishingRequest);
         
-        // Verify timestamp
-        require!(
-            clock.unix_timestamp <= timestamp + fishing::SIGNATURE_TIMEOUT,
-            TidesError::SignatureExpired
-        );
-        require!(timestamp <= clock.unix_timestamp, TidesError::FutureTimestamp);
+        let oracle_set = &ctx.accounts.oracle_set;
+        let required = if species > 0 {
+            let fish_species = ctx
+                .accounts
+                .fish_species
+                .as_ref()
+                .ok_or(TidesError::FishSpeciesRequired)?;
+            require!(
+                registries::is_valid_species(fish_species, species),
+                TidesError::InvalidSpecies
+            );
+            oracle_set.required_signatures(fish_species.base_price)
+        } else {
+            1
+        };
         
-        // Note: Full Ed25519 signature verification would go here
-        // For now, we'll verify the server signer matches
-        // In production, use solana_program::ed25519_program
+        let result = FishingResult {
+            player: ctx.accounts.player.key(),
+            nonce: fishing_state.pending_nonce,
+            species,
+            weight,
+            timestamp,
+        };
+        fishing::verify_fishing_signatures(
+            &result,
+            &ctx.accounts.game_state.key(),
+            oracle_set,
+            &ctx.accounts.instructions,
+            required,
+            &clock,
+        )?;
         
         let fishing_nonce = fishing_state.pending_nonce;
         fishing_state.pending_nonce = 0;
@@ -578,12 +1003,28 @@ pub mod tides {
             fish_catch.species = species;
             fish_catch.weight = weight;
             fish_catch.caught_timestamp = clock.unix_timestamp;
+            fish_catch.version = ACCOUNT_VERSION;
             
             emit!(FishCaught {
                 player: ctx.accounts.player.key(),
                 species,
                 weight,
             });
+        } else {
+            // Nothing went into the hold, so hand the rent straight back
+            ctx.accounts
+                .fish_catch
+                .close(ctx.accounts.player.to_account_info())?;
+        }
+        
+        if species > 0 {
+            season_pass::award_xp(
+                ctx.accounts.season.as_ref(),
+                ctx.accounts.player_stats.as_deref_mut(),
+                ctx.accounts.season_track.as_ref(),
+                season_pass::XpSource::Catch,
+                1,
+            )?;
         }
         
         Ok(instance_id)
@@ -594,10 +1035,18 @@ pub mod tides {
         bait_type: u64,
         amount: u64,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_SHOP)?;
         require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         require!(amount > 0, TidesError::InvalidAmount);
         
+        let via_session = session::authorize(
+            &ctx.accounts.player.key(),
+            &ctx.accounts.authority.key(),
+            ctx.accounts.session_token.as_ref(),
+            SESSION_PURCHASE_BAIT,
+            Clock::get()?.unix_timestamp,
+        )?;
+        
         let bait_type_account = &ctx.accounts.bait_type;
         require!(bait_type_account.bait_id == bait_type, TidesError::InvalidBait);
         require!(bait_type_account.is_active, TidesError::InvalidBait);
@@ -607,20 +1056,33 @@ pub mod tides {
             .ok_or(TidesError::MathOverflow)?;
         
         // Transfer tokens (burn)
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.player_token_account.to_account_info(),
-            to: ctx.accounts.game_token_account.to_account_info(),
-            authority: ctx.accounts.player.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, total_cost)?;
+        session::spend(
+            via_session,
+            ctx.accounts.session_token.as_mut(),
+            &ctx.accounts.authority,
+            &ctx.accounts.player_token_account,
+            &ctx.accounts.game_token_account,
+            &ctx.accounts.token_program,
+            total_cost,
+        )?;
         
         // Add bait to player inventory
         let player_bait = &mut ctx.accounts.player_bait;
+        player_bait.player = ctx.accounts.player.key();
         player_bait.bait_type = bait_type;
+        player_bait.version = ACCOUNT_VERSION;
         player_bait.amount = player_bait.amount.checked_add(amount).ok_or(TidesError::MathOverflow)?;
         
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        season_pass::record_activity(
+            ctx.accounts.season.as_ref(),
+            player_stats.as_mut(),
+            ctx.accounts.leaderboard.as_ref(),
+            0,
+            total_cost,
+        )?;
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
+
         emit!(BaitPurchased {
             player: ctx.accounts.player.key(),
             bait_type,
@@ -639,27 +1101,23 @@ pub mod tides {
         to_y: u8,
         rotation: u8,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_INVENTORY)?;
         require!(rotation < 4, TidesError::InvalidRotation);
+        session::authorize(
+            &ctx.accounts.player.key(),
+            &ctx.accounts.authority.key(),
+            ctx.accounts.session_token.as_ref(),
+            SESSION_INVENTORY,
+            Clock::get()?.unix_timestamp,
+        )?;
         
-        let inventory = &mut ctx.accounts.player_inventory;
-        let item = inventory::get_item_at(inventory, from_x, from_y)?;
-        require!(item.item_type != ItemType::Empty, TidesError::ItemNotFound);
-        
-        // Remove from old position
-        inventory::remove_item(inventory, item.instance_id)?;
-        
-        // Place at new position (simplified - would get shape from registry)
-        inventory::place_item(
-            inventory,
-            item.item_type,
-            item.item_id,
+        let item = inventory::move_item(
+            &mut ctx.accounts.player_inventory,
+            from_x,
+            from_y,
             to_x,
             to_y,
             rotation,
-            1, // shape_width (simplified)
-            1, // shape_height (simplified)
-            vec![], // shape_data (simplified)
         )?;
         
         emit!(ItemMoved {
@@ -680,17 +1138,22 @@ pub mod tides {
         x: u8,
         y: u8,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
-        
-        let inventory = &mut ctx.accounts.player_inventory;
-        let item = inventory::get_item_at(inventory, x, y)?;
-        require!(item.item_type != ItemType::Empty, TidesError::ItemNotFound);
+        ctx.accounts.game_state.require_unpaused(PAUSE_INVENTORY)?;
         
-        inventory::remove_item(inventory, item.instance_id)?;
+        let item = inventory::discard_item(&mut ctx.accounts.player_inventory, x, y)?;
         
         // If it's a fish, remove fish catch data
         if item.item_type == ItemType::Fish {
-            // Fish catch account would be closed/removed here
+            let fish_catch = ctx
+                .accounts
+                .fish_catch
+                .as_ref()
+                .ok_or(TidesError::FishCatchRequired)?;
+            require!(
+                fish_catch.instance_id == item.instance_id,
+                TidesError::ItemNotFound
+            );
+            fish_catch.close(ctx.accounts.player.to_account_info())?;
         }
         
         emit!(ItemDiscarded {
@@ -703,123 +1166,195 @@ pub mod tides {
         Ok(())
     }
 
-    pub fn purchase_season_pass(
-        ctx: Context<PurchaseSeasonPass>,
-        season_id: u64,
+    /// Runs `actions` in order against the player's state and inventory,
+    /// so a whole turn fits in one transaction; if any action fails the
+    /// batch reverts. Fish discarded by `Discard` need their `FishCatch`
+    /// accounts as remaining accounts, in action order.
+    pub fn execute_actions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteActions<'info>>,
+        actions: Vec<GameAction>,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
-        
-        let season = &mut ctx.accounts.season;
-        require!(season.season_id == season_id, TidesError::InvalidId);
-        
+        require!(actions::is_valid_batch(&actions), TidesError::InvalidActionBatch);
+        require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
+
         let clock = Clock::get()?;
-        require!(season.is_active, TidesError::SeasonNotActive);
-        require!(clock.unix_timestamp >= season.start_time, TidesError::SeasonNotStarted);
-        require!(clock.unix_timestamp <= season.end_time, TidesError::SeasonHasEnded);
-        
-        let stats = &mut ctx.accounts.player_stats;
-        require!(!stats.has_pass, TidesError::AlreadyOwnsSeasonPass);
-        
-        // Transfer SOL for season pass
-        require!(
-            ctx.accounts.player.lamports() >= season.pass_price,
-            TidesError::InsufficientPayment
-        );
-        
-        **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? -= season.pass_price;
-        **ctx.accounts.season_pass_state.to_account_info().try_borrow_mut_lamports()? += season.pass_price;
-        
-        // Initialize player stats
-        stats.player = ctx.accounts.player.key();
-        stats.season_id = season_id;
-        stats.total_earnings = 0;
-        stats.total_spent = 0;
-        stats.net_value = 0;
-        stats.last_update_time = clock.unix_timestamp;
-        stats.has_pass = true;
-        
-        season.total_passes = season.total_passes.checked_add(1).ok_or(TidesError::MathOverflow)?;
-        
-        emit!(SeasonPassPurchased {
-            player: ctx.accounts.player.key(),
-            season_id,
-        });
-        
-        Ok(())
-    }
+        let player = ctx.accounts.player.key();
+        let mut fish_catches = ctx.remaining_accounts.iter();
+        let mut compute_units = Vec::with_capacity(actions.len());
+        let mut hexes_sailed: u64 = 0;
+        let mut fuel_consumed: u64 = 0;
+        let mut fuel_purchased: u64 = 0;
+        let mut tokens_spent: u64 = 0;
 
-    // ============ Admin Functions ============
+        for action in &actions {
+            let units_before = sol_remaining_compute_units();
+            ctx.accounts.game_state.require_unpaused(action.pause_flag())?;
+            let via_session = session::authorize(
+                &player,
+                &ctx.accounts.authority.key(),
+                ctx.accounts.session_token.as_ref(),
+                action.session_permission(),
+                clock.unix_timestamp,
+            )?;
 
-    pub fn pause_game(ctx: Context<AdminOnly>) -> Result<()> {
-        let game_state = &mut ctx.accounts.game_state;
-        require!(!game_state.paused, TidesError::GamePaused);
-        game_state.paused = true;
-        Ok(())
-    }
+            match action {
+                GameAction::Move { directions } => {
+                    let fuel_cost = ctx
+                        .accounts
+                        .player_state
+                        .sail(&ctx.accounts.map, directions, clock.unix_timestamp)?;
+                    fuel_consumed = fuel_consumed
+                        .checked_add(fuel_cost)
+                        .ok_or(TidesError::MathOverflow)?;
+                    hexes_sailed += directions.len() as u64;
+                }
+                GameAction::InventoryMove { from_x, from_y, to_x, to_y, rotation } => {
+                    inventory::move_item(
+                        &mut ctx.accounts.player_inventory,
+                        *from_x,
+                        *from_y,
+                        *to_x,
+                        *to_y,
+                        *rotation,
+                    )?;
+                }
+                GameAction::Discard { x, y } => {
+                    let item = inventory::discard_item(&mut ctx.accounts.player_inventory, *x, *y)?;
+                    if item.item_type == ItemType::Fish {
+                        let fish_catch_info = fish_catches
+                            .next()
+                            .ok_or(TidesError::FishCatchRequired)?;
+                        let fish_catch =
+                            Account::<inventory::FishCatch>::try_from(fish_catch_info)?;
+                        require!(fish_catch.player == player, TidesError::InvalidPlayer);
+                        require!(
+                            fish_catch.instance_id == item.instance_id,
+                            TidesError::ItemNotFound
+                        );
+                        fish_catch.close(ctx.accounts.player.to_account_info())?;
+                    }
+                }
+                GameAction::PurchaseFuel { amount } => {
+                    require!(*amount > 0, TidesError::InvalidAmount);
+                    let cost = amount
+                        .checked_mul(FUEL_PRICE_PER_UNIT)
+                        .ok_or(TidesError::MathOverflow)?;
+                    session::spend(
+                        via_session,
+                        ctx.accounts.session_token.as_mut(),
+                        &ctx.accounts.authority,
+                        &ctx.accounts.player_token_account,
+                        &ctx.accounts.game_token_account,
+                        &ctx.accounts.token_program,
+                        cost,
+                    )?;
+                    ctx.accounts.player_state.add_fuel(*amount)?;
+                    fuel_purchased = fuel_purchased
+                        .checked_add(*amount)
+                        .ok_or(TidesError::MathOverflow)?;
+                    tokens_spent = tokens_spent
+                        .checked_add(cost)
+                        .ok_or(TidesError::MathOverflow)?;
+                }
+            }
 
-    pub fn unpause_game(ctx: Context<AdminOnly>) -> Result<()> {
-        let game_state = &mut ctx.accounts.game_state;
-        require!(game_state.paused, TidesError::GamePaused);
-        game_state.paused = false;
-        Ok(())
-    }
+            compute_units.push(units_before.saturating_sub(sol_remaining_compute_units()));
+        }
 
-    pub fn update_server_signer(
-        ctx: Context<AdminOnly>,
-        new_signer: Pubkey,
-    ) -> Result<()> {
-        require!(new_signer != Pubkey::default(), TidesError::InvalidAddress);
-        let game_state = &mut ctx.accounts.game_state;
-        game_state.server_signer = new_signer;
-        Ok(())
-    }
+        let mut player_stats = season_pass::load_current_stats(&ctx.accounts.player_stats)?;
+        if hexes_sailed > 0 {
+            season_pass::award_xp(
+                ctx.accounts.season.as_ref(),
+                player_stats.as_mut(),
+                ctx.accounts.season_track.as_ref(),
+                season_pass::XpSource::Sailing,
+                hexes_sailed,
+            )?;
+        }
+        if tokens_spent > 0 {
+            season_pass::record_activity(
+                ctx.accounts.season.as_ref(),
+                player_stats.as_mut(),
+                ctx.accounts.leaderboard.as_ref(),
+                0,
+                tokens_spent,
+            )?;
+        }
+        season_pass::store_current_stats(&ctx.accounts.player_stats, player_stats)?;
+
+        let player_state = &ctx.accounts.player_state;
+        emit!(ActionsExecuted {
+            player,
+            shard: player_state.shard,
+            map_id: player_state.map_id,
+            x: player_state.position_x,
+            y: player_state.position_y,
+            fuel_consumed,
+            fuel_purchased,
+            tokens_spent,
+            compute_units,
+        });
 
-    pub fn set_max_players_per_shard(
-        ctx: Context<AdminOnly>,
-        new_limit: u64,
-    ) -> Result<()> {
-        require!(new_limit > 0 && new_limit <= 10000, TidesError::LimitOutOfBounds);
-        let game_state = &mut ctx.accounts.game_state;
-        game_state.max_players_per_shard = new_limit;
         Ok(())
     }
 
-    pub fn admin_change_player_shard(
-        ctx: Context<AdminChangeShard>,
+    pub fn change_shard(
+        ctx: Context<ChangeShard>,
         new_shard: u8,
-        bypass_limit: bool,
     ) -> Result<()> {
-        require!(!ctx.accounts.game_state.paused, TidesError::GamePaused);
+        ctx.accounts.game_state.require_unpaused(PAUSE_REGISTRATION)?;
+        require!(ctx.accounts.player_state.is_active, TidesError::PlayerNotRegistered);
         require!(new_shard < MAX_SHARDS, TidesError::InvalidShardId);
         
-        let player_state = &mut ctx.accounts.player_state;
+        let clock = Clock::get()?;
+        let player_state = &ctx.accounts.player_state;
         let old_shard = player_state.shard;
         require!(new_shard != old_shard, TidesError::AlreadyInShard);
+        require!(
+            clock.unix_timestamp
+                >= player_state
+                    .last_shard_change
+                    .checked_add(SHARD_CHANGE_COOLDOWN)
+                    .ok_or(TidesError::MathOverflow)?,
+            TidesError::ShardChangeOnCooldown
+        );
         
-        // Set the new shard data's shard_id if it was just created
-        let new_shard_data = &mut ctx.accounts.new_shard_data;
-        if new_shard_data.shard_id == 0 {
-            new_shard_data.shard_id = new_shard;
-            new_shard_data.player_count = 0;
-        }
-        
-        let old_shard_data = &mut ctx.accounts.old_shard_data;
+        // Shard changes are only allowed while docked
+        let map = &ctx.accounts.map;
+        require!(map.map_id == player_state.map_id, TidesError::InvalidMap);
+        require!(
+            registries::is_harbor(map, player_state.position()),
+            TidesError::NotAtHarbor
+        );
+        require!(
+            ctx.accounts.fishing_state.pending_nonce == 0,
+            TidesError::PendingFishingRequest
+        );
         
-        if !bypass_limit {
-            require!(
-                new_shard_data.player_count < ctx.accounts.game_state.max_players_per_shard,
-                TidesError::ShardFull
-            );
-        }
+        let fee = ctx.accounts.game_state.shard_change_fee;
         
-        // Update shard counts
-        old_shard_data.player_count = old_shard_data.player_count.checked_sub(1).ok_or(TidesError::MathOverflow)?;
-        new_shard_data.player_count = new_shard_data.player_count.checked_add(1).ok_or(TidesError::MathOverflow)?;
+        // Pay the fee into the game treasury
+        if fee > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.player_token_account.to_account_info(),
+                to: ctx.accounts.game_token_account.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::transfer(cpi_ctx, fee)?;
+        }
         
-        player_state.shard = new_shard;
+        ctx.accounts.shard_directory.transfer_player(
+            &mut ctx.accounts.player_state,
+            &mut ctx.accounts.old_shard_data,
+            &mut ctx.accounts.new_shard_data,
+            ctx.accounts.game_state.max_players_per_shard,
+        )?;
+        ctx.accounts.player_state.last_shard_change = clock.unix_timestamp;
         
         emit!(ShardChanged {
-            player: player_state.player,
+            player: ctx.accounts.player.key(),
             old_shard,
             new_shard,
         });
@@ -827,491 +1362,3607 @@ pub mod tides {
         Ok(())
     }
 
-    // ============ Helper Functions ============
+    /// Closes `FishCatch` accounts passed in `remaining_accounts` whose fish
+    /// is no longer in the player's hold, returning their rent.
+    pub fn sweep_fish_catches<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepFishCatches<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_INVENTORY)?;
+        let player = ctx.accounts.player.key();
+        let inventory = &ctx.accounts.player_inventory;
+        let mut reclaimed: u64 = 0;
+
+        for catch_info in ctx.remaining_accounts {
+            let fish_catch = Account::<inventory::FishCatch>::try_from(catch_info)?;
+            require!(fish_catch.player == player, TidesError::InvalidPlayer);
+            require!(
+                !inventory::contains_instance(inventory, fish_catch.instance_id),
+                TidesError::FishCatchInUse
+            );
 
-    fn calculate_fuel_cost(distance: u64) -> u64 {
-        distance
-            .checked_mul(HEX_MOVE_COST)
-            .unwrap_or(u64::MAX)
+            fish_catch.close(ctx.accounts.player.to_account_info())?;
+            reclaimed = reclaimed.checked_add(1).ok_or(TidesError::MathOverflow)?;
+        }
+
+        emit!(FishCatchesSwept { player, reclaimed });
+
+        Ok(())
     }
 
-    fn calculate_fish_freshness(caught_at: i64, current_time: i64) -> u64 {
-        let seconds_elapsed = current_time
-            .checked_sub(caught_at)
-            .unwrap_or(0);
+    pub fn purchase_season_pass(
+        ctx: Context<PurchaseSeasonPass>,
+        season_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_SEASONS)?;
         
-        if seconds_elapsed < 0 {
-            return 100;
-        }
+        let season = &ctx.accounts.season;
+        require!(season.season_id == season_id, TidesError::InvalidId);
+        
+        let clock = Clock::get()?;
+        season_pass::require_pass_sale_open(season, &clock)?;
+        
+        // Transfer SOL for season pass into the season's prize pool vault
+        let price = season.pass_price;
+        require!(
+            ctx.accounts.player.lamports() >= price,
+            TidesError::InsufficientPayment
+        );
+        
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.player.to_account_info(),
+            to: ctx.accounts.season_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_ctx, price)?;
+        
+        season_pass::mint_pass(
+            &ctx.accounts.season,
+            ctx.bumps.season,
+            &ctx.accounts.pass_mint,
+            &ctx.accounts.pass_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        
+        let season = &mut ctx.accounts.season;
+        let prize_share = price
+            .checked_mul(season.prize_pool_bps as u64)
+            .and_then(|p| p.checked_div(season_pass::BASIS_POINTS))
+            .ok_or(TidesError::MathOverflow)?;
+        season.prize_pool = season.prize_pool.checked_add(prize_share).ok_or(TidesError::MathOverflow)?;
+        season.total_revenue = season.total_revenue.checked_add(price).ok_or(TidesError::MathOverflow)?;
+        
+        season_pass::grant_pass(
+            &mut ctx.accounts.player_stats,
+            season,
+            ctx.accounts.player.key(),
+            ctx.accounts.pass_mint.key(),
+            &clock,
+        )?;
+        
+        emit!(SeasonPassPurchased {
+            player: ctx.accounts.player.key(),
+            season_id,
+        });
+        
+        Ok(())
+    }
 
-        let decay_periods = seconds_elapsed
-            .checked_div(FRESHNESS_DECAY_PERIOD)
-            .unwrap_or(0);
+    pub fn purchase_season_pass_with_tokens(
+        ctx: Context<PurchaseSeasonPassWithTokens>,
+        season_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_SEASONS)?;
         
-        let freshness_decayed = (decay_periods as u64)
-            .checked_mul(FRESHNESS_DECAY_RATE)
-            .unwrap_or(100);
+        let season = &ctx.accounts.season;
+        require!(season.season_id == season_id, TidesError::InvalidId);
+        
+        let clock = Clock::get()?;
+        season_pass::require_pass_sale_open(season, &clock)?;
+        
+        let cost = season.token_pass_price;
+        require!(cost > 0, TidesError::TokenPaymentDisabled);
+        
+        // Pay the pass price into the game treasury
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.player_token_account.to_account_info(),
+            to: ctx.accounts.game_token_account.to_account_info(),
+            authority: ctx.accounts.player.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, cost)?;
+        
+        season_pass::mint_pass(
+            &ctx.accounts.season,
+            ctx.bumps.season,
+            &ctx.accounts.pass_mint,
+            &ctx.accounts.pass_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        
+        season_pass::grant_pass(
+            &mut ctx.accounts.player_stats,
+            &mut ctx.accounts.season,
+            ctx.accounts.player.key(),
+            ctx.accounts.pass_mint.key(),
+            &clock,
+        )?;
+        
+        emit!(SeasonPassPurchased {
+            player: ctx.accounts.player.key(),
+            season_id,
+        });
+        
+        Ok(())
+    }
 
-        if freshness_decayed >= 100 {
-            return 0;
+    /// Clears the pass from stats whose player no longer holds its token and
+    /// drops them from the leaderboard until they hold a pass again. Once
+    /// the season is finalized the board is frozen, and the missing pass
+    /// alone keeps them from claiming. Anyone can sync.
+    pub fn sync_season_pass(ctx: Context<SyncSeasonPass>) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_SEASONS)?;
+        let season = &ctx.accounts.season;
+        let stats = &mut ctx.accounts.player_stats;
+        require!(stats.has_pass, TidesError::NoSeasonPass);
+
+        // Pass mints have a supply of one, so another holder proves the
+        // player no longer has it
+        let pass_token_account = &ctx.accounts.pass_token_account;
+        require!(
+            stats.token_id == Some(pass_token_account.mint)
+                && pass_token_account.amount == 1
+                && pass_token_account.owner != stats.player,
+            TidesError::PassStillHeld
+        );
+
+        stats.has_pass = false;
+        stats.token_id = None;
+
+        if !season.is_finalized && season.leaderboard != Pubkey::default() {
+            let leaderboard = ctx
+                .accounts
+                .leaderboard
+                .as_ref()
+                .ok_or(TidesError::LeaderboardRequired)?;
+            require!(leaderboard.key() == season.leaderboard, TidesError::InvalidAddress);
+
+            if let Some(old_rank) = leaderboard.load_mut()?.remove(&stats.player) {
+                emit!(LeaderboardEntryRemoved {
+                    season_id: stats.season_id,
+                    player: stats.player,
+                    old_rank: old_rank as u32 + 1,
+                });
+            }
         }
 
-        100 - freshness_decayed
+        emit!(SeasonPassTransferred {
+            season_id: stats.season_id,
+            pass_mint: pass_token_account.mint,
+            from: stats.player,
+            to: pass_token_account.owner,
+        });
+
+        Ok(())
     }
 
-    fn update_fish_market_data(
-        market_data: &mut Account<FishMarketData>,
-        species: u64,
-        weight: u16,
-        freshness: u64,
-        current_timestamp: i64,
-    ) -> Result<u64> {
-        let base_price = 100_000_000; // Simplified - would come from fish registry
+    /// Moves a traded pass onto the stats of whoever now holds its token.
+    /// Only allowed before the season starts, while the stats are still empty.
+    pub fn claim_season_pass(
+        ctx: Context<ClaimSeasonPass>,
+        season_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_SEASONS)?;
         
-        let sale_price = if market_data.last_sold_timestamp == 0 {
-            // First sale
-            base_price
-                .checked_mul(weight as u64)
-                .and_then(|p| p.checked_mul(freshness))
-                .and_then(|p| p.checked_div(100))
-                .ok_or(TidesError::MathOverflow)?;
-            
-            market_data.value = base_price
-                .checked_sub(base_price.checked_mul(PRICE_DECAY_RATE).and_then(|p| p.checked_div(100)).unwrap_or(0))
-                .unwrap_or(0);
-            
-            base_price
-                .checked_mul(weight as u64)
-                .and_then(|p| p.checked_mul(freshness))
-                .and_then(|p| p.checked_div(100))
-                .ok_or(TidesError::MathOverflow)?
-        } else {
-            // Calculate market recovery
-            let seconds_elapsed = current_timestamp
-                .checked_sub(market_data.last_sold_timestamp)
-                .unwrap_or(0);
-            
-            let market_value = market_data.value
-                .checked_add(base_price.checked_mul(PRICE_RECOVERY_RATE).and_then(|p| p.checked_mul(seconds_elapsed as u64)).and_then(|p| p.checked_div(10_000_000)).unwrap_or(0))
-                .unwrap_or(market_data.value);
-            
-            let capped_value = market_value.min(base_price);
-            
-            let sale_price = capped_value
-                .checked_mul(weight as u64)
-                .and_then(|p| p.checked_mul(freshness))
-                .and_then(|p| p.checked_div(100))
-                .ok_or(TidesError::MathOverflow)?;
-            
-            market_data.value = capped_value
-                .checked_sub(capped_value.checked_mul(PRICE_DECAY_RATE).and_then(|p| p.checked_div(100)).unwrap_or(0))
-                .unwrap_or(0);
-            
-            sale_price
+        let season = &ctx.accounts.season;
+        require!(season.season_id == season_id, TidesError::InvalidId);
+        
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp < season.start_time, TidesError::SeasonAlreadyStarted);
+        
+        let holder = ctx.accounts.holder.key();
+        let previous_stats = &mut ctx.accounts.previous_stats;
+        require!(previous_stats.player != holder, TidesError::AlreadyOwnsSeasonPass);
+        require!(previous_stats.has_pass, TidesError::NoSeasonPass);
+        require!(
+            season_pass::holds_pass(previous_stats, &ctx.accounts.pass_token_account, &holder),
+            TidesError::NoSeasonPass
+        );
+        
+        let previous_owner = previous_stats.player;
+        let pass_mint = ctx.accounts.pass_token_account.mint;
+        previous_stats.has_pass = false;
+        previous_stats.token_id = None;
+        
+        let stats = &mut ctx.accounts.holder_stats;
+        require!(!stats.has_pass, TidesError::AlreadyOwnsSeasonPass);
+        stats.player = holder;
+        stats.season_id = season_id;
+        stats.last_update_time = clock.unix_timestamp;
+        stats.has_pass = true;
+        stats.token_id = Some(pass_mint);
+        stats.reward_claimed = false;
+        stats.version = ACCOUNT_VERSION;
+        
+        emit!(SeasonPassTransferred {
+            season_id,
+            pass_mint,
+            from: previous_owner,
+            to: holder,
+        });
+        
+        Ok(())
+    }
+
+    /// Creates the player's season stats without a pass so they can earn
+    /// XP and claim the free lane of the season track.
+    pub fn join_season(ctx: Context<JoinSeason>, season_id: u64) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_SEASONS)?;
+        
+        let season = &ctx.accounts.season;
+        require!(season.is_active, TidesError::SeasonNotActive);
+        
+        let clock = Clock::get()?;
+        let stats = &mut ctx.accounts.player_stats;
+        stats.player = ctx.accounts.player.key();
+        stats.season_id = season_id;
+        stats.last_update_time = clock.unix_timestamp;
+        stats.version = ACCOUNT_VERSION;
+        
+        emit!(SeasonJoined {
+            season_id,
+            player: stats.player,
+        });
+        
+        Ok(())
+    }
+
+    /// Claims the free or premium reward of `tier` once the player has
+    /// enough season XP. Bait, fuel and currency rewards are delivered to
+    /// the matching optional account; cosmetics are unlocked off-chain from
+    /// the emitted event.
+    pub fn claim_tier_reward(
+        ctx: Context<ClaimTierReward>,
+        season_id: u64,
+        tier: u8,
+        premium: bool,
+    ) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_SEASONS)?;
+        season_pass::require_track_claim_open(&ctx.accounts.season, &Clock::get()?)?;
+        
+        let reward = season_pass::claim_tier(
+            &mut ctx.accounts.player_stats,
+            &ctx.accounts.season_track,
+            ctx.accounts.pass_token_account.as_deref(),
+            tier,
+            premium,
+        )?;
+        
+        match reward.kind {
+            season_pass::TierRewardKind::Bait => {
+                let player_bait = ctx
+                    .accounts
+                    .player_bait
+                    .as_mut()
+                    .ok_or(TidesError::RewardAccountRequired)?;
+                player_bait.player = ctx.accounts.player.key();
+                player_bait.bait_type = reward.item_id;
+                player_bait.version = ACCOUNT_VERSION;
+                player_bait.amount = player_bait
+                    .amount
+                    .checked_add(reward.amount)
+                    .ok_or(TidesError::MathOverflow)?;
+            }
+            season_pass::TierRewardKind::Fuel => {
+                let player_state = ctx
+                    .accounts
+                    .player_state
+                    .as_mut()
+                    .ok_or(TidesError::RewardAccountRequired)?;
+                player_state.current_fuel = player_state
+                    .current_fuel
+                    .checked_add(reward.amount)
+                    .ok_or(TidesError::MathOverflow)?;
+            }
+            season_pass::TierRewardKind::Currency => {
+                let reward_token_account = ctx
+                    .accounts
+                    .reward_token_account
+                    .as_ref()
+                    .ok_or(TidesError::RewardAccountRequired)?;
+                let player_token_account = ctx
+                    .accounts
+                    .player_token_account
+                    .as_ref()
+                    .ok_or(TidesError::RewardAccountRequired)?;
+                
+                let season_id_bytes = season_id.to_le_bytes();
+                let signer_seeds: &[&[&[u8]]] = &[&[
+                    b"season_track",
+                    &season_id_bytes,
+                    &[ctx.bumps.season_track],
+                ]];
+                let cpi_accounts = Transfer {
+                    from: reward_token_account.to_account_info(),
+                    to: player_token_account.to_account_info(),
+                    authority: ctx.accounts.season_track.to_account_info(),
+                };
+                let cpi_program = ctx.accounts.token_program.to_account_info();
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                token::transfer(cpi_ctx, reward.amount)?;
+            }
+            season_pass::TierRewardKind::Cosmetic | season_pass::TierRewardKind::None => {}
+        }
+        
+        emit!(TierRewardClaimed {
+            season_id,
+            player: ctx.accounts.player.key(),
+            tier,
+            premium,
+            kind: reward.kind,
+            item_id: reward.item_id,
+            amount: reward.amount,
+        });
+        
+        Ok(())
+    }
+
+    // ============ Session Keys ============
+
+    /// Delegates gameplay to `session_key` until `expires_at`. The session
+    /// may call the `allowed_instructions` (`SESSION_*` bits) and spend up
+    /// to `spend_cap` game tokens, approved to the session PDA as delegate.
+    /// Replaces any existing session of the player.
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        expires_at: i64,
+        spend_cap: u64,
+        allowed_instructions: u16,
+    ) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_REGISTRATION)?;
+        let player = ctx.accounts.player.key();
+        let clock = Clock::get()?;
+        session::validate_session(
+            &player,
+            &session_key,
+            expires_at,
+            allowed_instructions,
+            clock.unix_timestamp,
+        )?;
+
+        let session_token = &mut ctx.accounts.session_token;
+        session_token.player = player;
+        session_token.session_key = session_key;
+        session_token.expires_at = expires_at;
+        session_token.spend_cap = spend_cap;
+        session_token.spent = 0;
+        session_token.allowed_instructions = allowed_instructions;
+        session_token.bump = ctx.bumps.session_token;
+        session_token.version = ACCOUNT_VERSION;
+
+        let cpi_accounts = Approve {
+            to: ctx.accounts.player_token_account.to_account_info(),
+            delegate: ctx.accounts.session_token.to_account_info(),
+            authority: ctx.accounts.player.to_account_info(),
         };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::approve(CpiContext::new(cpi_program, cpi_accounts), spend_cap)?;
 
-        market_data.last_sold_timestamp = current_timestamp;
-        market_data.species = species;
+        emit!(SessionCreated {
+            player,
+            session_key,
+            expires_at,
+            spend_cap,
+            allowed_instructions,
+        });
+        Ok(())
+    }
 
-        Ok(sale_price)
+    /// Ends the player's session and removes its token delegation.
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_REGISTRATION)?;
+        let session_token = &ctx.accounts.session_token;
+        if ctx.accounts.player_token_account.delegate == Some(session_token.key()).into() {
+            let cpi_accounts = Revoke {
+                source: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::revoke(CpiContext::new(cpi_program, cpi_accounts))?;
+        }
+
+        emit!(SessionRevoked {
+            player: ctx.accounts.player.key(),
+            session_key: session_token.session_key,
+            spent: session_token.spent,
+        });
+        Ok(())
     }
-}
 
-// ============ Contexts ============
+    // ============ Season Admin Functions ============
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
+    pub fn initialize_season_pass(
+        ctx: Context<InitializeSeasonPass>,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        require!(treasury != Pubkey::default(), TidesError::InvalidAddress);
+        let season_pass_state = &mut ctx.accounts.season_pass_state;
+        season_pass_state.admin = ctx.accounts.admin.key();
+        season_pass_state.current_season_id = 0;
+        season_pass_state.paused = false;
+        season_pass_state.treasury = treasury;
+        season_pass_state.pending_admin = Pubkey::default();
+        season_pass_state.version = ACCOUNT_VERSION;
+        Ok(())
+    }
+
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u64,
+        name: String,
+        start_time: i64,
+        end_time: i64,
+        pass_price: u64,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        // Season 0 is reserved to mean "no current season"
+        require!(season_id > 0, TidesError::InvalidId);
+
+        let clock = Clock::get()?;
+        season_pass::create_season(
+            &mut ctx.accounts.season,
+            season_id,
+            name.clone(),
+            start_time,
+            end_time,
+            pass_price,
+            &clock,
+        )?;
+        ctx.accounts.season.collection_mint = ctx.accounts.collection_mint.key();
+        ctx.accounts.season_vault.season_id = season_id;
+        ctx.accounts.season_vault.version = ACCOUNT_VERSION;
+
+        emit!(SeasonCreated {
+            season_id,
+            name,
+            start_time,
+            end_time,
+            pass_price,
+        });
+
+        Ok(())
+    }
+
+    pub fn activate_season(ctx: Context<SeasonAdmin>) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        let season_pass_state = &mut ctx.accounts.season_pass_state;
+        require!(season_pass_state.current_season_id == 0, TidesError::SeasonAlreadyInProgress);
+
+        let clock = Clock::get()?;
+        let season = &mut ctx.accounts.season;
+        season_pass::activate_season(season, &clock)?;
+        season_pass_state.current_season_id = season.season_id;
+
+        emit!(SeasonActivated {
+            season_id: season.season_id,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_season(
+        ctx: Context<SeasonAdmin>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        pass_price: Option<u64>,
+        token_pass_price: Option<u64>,
+        prize_pool_bps: Option<u16>,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        let clock = Clock::get()?;
+        let season = &mut ctx.accounts.season;
+        season_pass::update_season(
+            season,
+            start_time,
+            end_time,
+            pass_price,
+            token_pass_price,
+            prize_pool_bps,
+            &clock,
+        )?;
+
+        emit!(SeasonUpdated {
+            season_id: season.season_id,
+            start_time: season.start_time,
+            end_time: season.end_time,
+            pass_price: season.pass_price,
+            token_pass_price: season.token_pass_price,
+            prize_pool_bps: season.prize_pool_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Initializes a leaderboard account the admin has already created with
+    /// `SeasonLeaderboard::LEN` bytes, and links it to the season.
+    pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        let season = &mut ctx.accounts.season;
+        require!(season.leaderboard == Pubkey::default(), TidesError::LeaderboardAlreadyInitialized);
+
+        let mut board = ctx.accounts.leaderboard.load_init()?;
+        board.season_id = season.season_id;
+        board.count = 0;
+        board.version = ACCOUNT_VERSION;
+        season.leaderboard = ctx.accounts.leaderboard.key();
+
+        Ok(())
+    }
+
+    /// Defines the season's battle-pass track. Tiers must be sorted by
+    /// strictly increasing XP and cannot change once created.
+    pub fn create_season_track(
+        ctx: Context<CreateSeasonTrack>,
+        xp_per_catch: u64,
+        xp_per_sale: u64,
+        xp_per_hex: u64,
+        tiers: Vec<season_pass::TrackTier>,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        let season = &ctx.accounts.season;
+        require!(!season.has_ended, TidesError::SeasonAlreadyEnded);
+        
+        let tier_count = tiers.len() as u8;
+        season_pass::create_track(
+            &mut ctx.accounts.season_track,
+            season.season_id,
+            xp_per_catch,
+            xp_per_sale,
+            xp_per_hex,
+            tiers,
+        )?;
+        
+        emit!(SeasonTrackCreated {
+            season_id: season.season_id,
+            tier_count,
+            xp_per_catch,
+            xp_per_sale,
+            xp_per_hex,
+        });
+        
+        Ok(())
+    }
+
+    /// Returns the 1-based leaderboard rank of `player`, if ranked.
+    pub fn get_player_position(
+        ctx: Context<GetPlayerPosition>,
+        player: Pubkey,
+    ) -> Result<Option<u32>> {
+        let board = ctx.accounts.leaderboard.load()?;
+        Ok(board.position(&player).map(|rank| rank as u32 + 1))
+    }
+
+    /// Freezes an ended season's results: snapshots the prize pool and fixes
+    /// the payout curve and claim window. The leaderboard no longer changes
+    /// once the season has ended, so its final order is the payout order.
+    pub fn finalize_season(
+        ctx: Context<FinalizeSeason>,
+        payout_ratio_bps: u16,
+        claim_period: i64,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        require!(
+            payout_ratio_bps > 0 && payout_ratio_bps as u64 <= season_pass::BASIS_POINTS,
+            TidesError::LimitOutOfBounds
+        );
+        require!(claim_period > 0, TidesError::InvalidTimeRange);
+
+        let season = &mut ctx.accounts.season;
+        require!(season.has_ended, TidesError::SeasonNotEnded);
+        require!(!season.is_finalized, TidesError::SeasonAlreadyFinalized);
+
+        let board = ctx.accounts.leaderboard.load()?;
+        let clock = Clock::get()?;
+
+        season.is_finalized = true;
+        season.reward_pool = season.prize_pool;
+        season.claimed_rewards = 0;
+        season.payout_ratio_bps = payout_ratio_bps;
+        season.reward_count = board.count.min(TOP_REWARDS_COUNT as u32);
+        season.claim_deadline = clock
+            .unix_timestamp
+            .checked_add(claim_period)
+            .ok_or(TidesError::MathOverflow)?;
+
+        emit!(SeasonFinalized {
+            season_id: season.season_id,
+            reward_pool: season.reward_pool,
+            reward_count: season.reward_count,
+            payout_ratio_bps,
+            claim_deadline: season.claim_deadline,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_season_reward(ctx: Context<ClaimSeasonReward>) -> Result<u64> {
+        ctx.accounts.game_state.require_unpaused(PAUSE_SEASONS)?;
+        let season = &ctx.accounts.season;
+        require!(season.is_finalized, TidesError::SeasonNotFinalized);
+
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp <= season.claim_deadline, TidesError::ClaimPeriodOver);
+
+        let stats = &mut ctx.accounts.player_stats;
+        require!(!stats.reward_claimed, TidesError::RewardAlreadyClaimed);
+        // A pass sold after finalization still leaves the seller on the
+        // frozen board
+        require!(stats.has_pass, TidesError::NoSeasonPass);
+
+        let player = ctx.accounts.player.key();
+        let rank = ctx
+            .accounts
+            .leaderboard
+            .load()?
+            .position(&player)
+            .map(|rank| rank as u32)
+            .filter(|rank| *rank < season.reward_count)
+            .ok_or(TidesError::NotEligibleForReward)?;
+
+        let amount = season_pass::reward_share(
+            season.reward_pool,
+            rank,
+            season.reward_count,
+            season.payout_ratio_bps,
+        );
+        stats.reward_claimed = true;
+
+        let vault_info = ctx.accounts.season_vault.to_account_info();
+        let player_info = ctx.accounts.player.to_account_info();
+        **vault_info.try_borrow_mut_lamports()? = vault_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(TidesError::InsufficientBalance)?;
+        **player_info.try_borrow_mut_lamports()? = player_info
+            .lamports()
+            .checked_add(amount)
+            .ok_or(TidesError::MathOverflow)?;
+
+        let season = &mut ctx.accounts.season;
+        season.claimed_rewards = season
+            .claimed_rewards
+            .checked_add(amount)
+            .ok_or(TidesError::MathOverflow)?;
+
+        emit!(SeasonRewardClaimed {
+            season_id: season.season_id,
+            player,
+            rank: rank + 1,
+            amount,
+        });
+
+        Ok(amount)
+    }
+
+    /// Sends rewards nobody claimed before the deadline to the treasury.
+    pub fn sweep_unclaimed_rewards(ctx: Context<SweepUnclaimedRewards>) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        let season = &mut ctx.accounts.season;
+        require!(season.is_finalized, TidesError::SeasonNotFinalized);
+
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp > season.claim_deadline, TidesError::ClaimPeriodActive);
+
+        let amount = season
+            .reward_pool
+            .checked_sub(season.claimed_rewards)
+            .ok_or(TidesError::MathOverflow)?;
+
+        let vault_info = ctx.accounts.season_vault.to_account_info();
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        **vault_info.try_borrow_mut_lamports()? = vault_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(TidesError::InsufficientBalance)?;
+        **treasury_info.try_borrow_mut_lamports()? = treasury_info
+            .lamports()
+            .checked_add(amount)
+            .ok_or(TidesError::MathOverflow)?;
+
+        season.claimed_rewards = season.reward_pool;
+
+        emit!(UnclaimedRewardsSwept {
+            season_id: season.season_id,
+            treasury: ctx.accounts.treasury.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraws pass revenue that is not part of the prize pool to the
+    /// treasury. The vault keeps its rent-exempt minimum and every lamport
+    /// still owed to pass holders.
+    pub fn withdraw_season_revenue(
+        ctx: Context<WithdrawSeasonRevenue>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::EconomyManager)?;
+
+        require!(amount > 0, TidesError::InvalidAmount);
+
+        let season = &mut ctx.accounts.season;
+        require!(amount <= season.withdrawable_revenue(), TidesError::InsufficientBalance);
+
+        let vault_info = ctx.accounts.season_vault.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+        let reserved = rent_exempt_minimum
+            .checked_add(season.outstanding_prizes())
+            .ok_or(TidesError::MathOverflow)?;
+        let remaining = vault_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(TidesError::InsufficientBalance)?;
+        require!(remaining >= reserved, TidesError::InsufficientBalance);
+
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        **vault_info.try_borrow_mut_lamports()? = remaining;
+        **treasury_info.try_borrow_mut_lamports()? = treasury_info
+            .lamports()
+            .checked_add(amount)
+            .ok_or(TidesError::MathOverflow)?;
+
+        season.withdrawn_revenue = season
+            .withdrawn_revenue
+            .checked_add(amount)
+            .ok_or(TidesError::MathOverflow)?;
+
+        emit!(SeasonRevenueWithdrawn {
+            season_id: season.season_id,
+            admin: ctx.accounts.admin.key(),
+            treasury: ctx.accounts.treasury.key(),
+            amount,
+            total_withdrawn: season.withdrawn_revenue,
+        });
+
+        Ok(())
+    }
+
+    pub fn end_season(ctx: Context<SeasonAdmin>) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SeasonManager)?;
+
+        let season_pass_state = &mut ctx.accounts.season_pass_state;
+        let season = &mut ctx.accounts.season;
+        require!(
+            season_pass_state.current_season_id == season.season_id,
+            TidesError::SeasonNotActive
+        );
+
+        season_pass::end_season(season)?;
+        season_pass_state.current_season_id = 0;
+        season_pass_state.last_ended_season_id = season.season_id;
+
+        emit!(SeasonEnded {
+            season_id: season.season_id,
+            total_passes: season.total_passes,
+        });
+
+        Ok(())
+    }
+
+    // ============ Admin Functions ============
+
+    pub fn grant_role(
+        ctx: Context<ManageRoles>,
+        account: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SuperAdmin)?;
+        require!(!role.is_timelocked(), TidesError::RoleRequiresTimelock);
+
+        if access_control::grant_role(&mut ctx.accounts.access_control, account, role)? {
+            emit!(RoleGranted {
+                account,
+                role,
+                granted_by: ctx.accounts.admin.key(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn revoke_role(
+        ctx: Context<ManageRoles>,
+        account: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SuperAdmin)?;
+        require!(!role.is_timelocked(), TidesError::RoleRequiresTimelock);
+
+        if access_control::revoke_role(&mut ctx.accounts.access_control, account, role)? {
+            emit!(RoleRevoked {
+                account,
+                role,
+                revoked_by: ctx.accounts.admin.key(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Proposes `new_admin` for the game state, the season pass state or a
+    /// registry. Nothing changes until they call `accept_admin`. The game
+    /// admin must also be a SuperAdmin to propose; no role moves with any
+    /// admin, since SuperAdmin is only granted through `propose_change`.
+    pub fn propose_admin(
+        ctx: Context<TransferAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let result = access_control::apply_admin_op(
+            &ctx.accounts.target,
+            &authority,
+            AdminOp::Propose(new_admin),
+        )?;
+        if result.is_game_state {
+            ctx.accounts.access_control.require_role(&authority, Role::SuperAdmin)?;
+        }
+
+        emit!(AdminTransferProposed {
+            account: ctx.accounts.target.key(),
+            admin: result.admin,
+            pending_admin: new_admin,
+        });
+        Ok(())
+    }
+
+    pub fn accept_admin(ctx: Context<TransferAdmin>) -> Result<()> {
+        let new_admin = ctx.accounts.authority.key();
+        let result = access_control::apply_admin_op(
+            &ctx.accounts.target,
+            &new_admin,
+            AdminOp::Accept,
+        )?;
+
+        emit!(AdminTransferred {
+            account: ctx.accounts.target.key(),
+            previous_admin: result.admin,
+            new_admin,
+        });
+        Ok(())
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<TransferAdmin>) -> Result<()> {
+        let result = access_control::apply_admin_op(
+            &ctx.accounts.target,
+            &ctx.accounts.authority.key(),
+            AdminOp::Cancel,
+        )?;
+
+        emit!(AdminProposalCancelled {
+            account: ctx.accounts.target.key(),
+            admin: result.admin,
+            cancelled_admin: result.pending_admin,
+        });
+        Ok(())
+    }
+
+    /// Pauses the subsystems in `flags` (`PAUSE_*` bits, `PAUSE_ALL` for
+    /// everything). `reason_code` is recorded in the event for incident logs.
+    pub fn pause_game(
+        ctx: Context<AdminOnly>,
+        flags: u8,
+        reason_code: u16,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::Pauser)?;
+        require!(flags != 0 && flags & !PAUSE_ALL == 0, TidesError::InvalidPauseFlags);
+
+        let game_state = &mut ctx.accounts.game_state;
+        require!(game_state.paused & flags != flags, TidesError::GamePaused);
+        game_state.paused |= flags;
+
+        emit!(SubsystemsPaused {
+            flags,
+            paused: game_state.paused,
+            reason_code,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    pub fn unpause_game(
+        ctx: Context<AdminOnly>,
+        flags: u8,
+        reason_code: u16,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::Pauser)?;
+        require!(flags != 0 && flags & !PAUSE_ALL == 0, TidesError::InvalidPauseFlags);
+
+        let game_state = &mut ctx.accounts.game_state;
+        require!(game_state.paused & flags != 0, TidesError::GameNotPaused);
+        game_state.paused &= !flags;
+
+        emit!(SubsystemsUnpaused {
+            flags,
+            paused: game_state.paused,
+            reason_code,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Queues a sensitive parameter change. It can be executed once
+    /// `GameState.timelock_delay` has passed, unless a guardian vetoes it.
+    pub fn propose_change(
+        ctx: Context<ProposeChange>,
+        change: ParameterChange,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), change.required_role())?;
+
+        let clock = Clock::get()?;
+        let pending_change = &mut ctx.accounts.pending_change;
+        governance::queue_change(
+            pending_change,
+            &mut ctx.accounts.game_state,
+            change,
+            ctx.accounts.admin.key(),
+            &clock,
+        )?;
+
+        emit!(ChangeProposed {
+            change_id: pending_change.change_id,
+            change,
+            proposer: pending_change.proposer,
+            executable_at: pending_change.executable_at,
+        });
+        Ok(())
+    }
+
+    /// Applies a queued change after its timelock. Anyone may execute it.
+    pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+        let pending_change = &mut ctx.accounts.pending_change;
+        require!(pending_change.status == ChangeStatus::Pending, TidesError::ChangeNotPending);
+
+        let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp >= pending_change.executable_at,
+            TidesError::TimelockNotExpired
+        );
+        require!(
+            clock.unix_timestamp <= pending_change.expires_at,
+            TidesError::ChangeExpired
+        );
+
+        governance::apply_change(
+            pending_change,
+            &mut ctx.accounts.game_state,
+            ctx.accounts.season_pass_state.as_mut(),
+            ctx.accounts.oracle_set.as_mut(),
+            ctx.accounts.access_control.as_mut(),
+            &clock,
+        )?;
+        pending_change.status = ChangeStatus::Executed;
+
+        emit!(ChangeExecuted {
+            change_id: pending_change.change_id,
+            change: pending_change.change,
+            executor: ctx.accounts.executor.key(),
+        });
+        Ok(())
+    }
+
+    pub fn veto_change(ctx: Context<VetoChange>) -> Result<()> {
+        ctx.accounts
+            .access_control
+            .require_explicit_role(&ctx.accounts.guardian.key(), Role::Guardian)?;
+
+        let pending_change = &mut ctx.accounts.pending_change;
+        require!(pending_change.status == ChangeStatus::Pending, TidesError::ChangeNotPending);
+        pending_change.status = ChangeStatus::Vetoed;
+
+        emit!(ChangeVetoed {
+            change_id: pending_change.change_id,
+            change: pending_change.change,
+            guardian: ctx.accounts.guardian.key(),
+        });
+        Ok(())
+    }
+
+    pub fn admin_change_player_shard(
+        ctx: Context<AdminChangeShard>,
+        new_shard: u8,
+        bypass_limit: bool,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::ShardOperator)?;
+
+        ctx.accounts.game_state.require_unpaused(PAUSE_REGISTRATION)?;
+        require!(new_shard < MAX_SHARDS, TidesError::InvalidShardId);
+        
+        let old_shard = ctx.accounts.player_state.shard;
+        require!(new_shard != old_shard, TidesError::AlreadyInShard);
+        
+        // Update shard counts
+        let max_players = if bypass_limit {
+            u64::MAX
+        } else {
+            ctx.accounts.game_state.max_players_per_shard
+        };
+        ctx.accounts.shard_directory.transfer_player(
+            &mut ctx.accounts.player_state,
+            &mut ctx.accounts.old_shard_data,
+            &mut ctx.accounts.new_shard_data,
+            max_players,
+        )?;
+        
+        emit!(ShardChanged {
+            player: ctx.accounts.player_state.player,
+            old_shard,
+            new_shard,
+        });
+        
+        Ok(())
+    }
+
+    /// Moves the players passed in `remaining_accounts` towards the
+    /// least-populated open shards.
+    ///
+    /// `remaining_accounts` holds `shard_account_count` `ShardData` accounts
+    /// followed by the `PlayerState` accounts to consider. With
+    /// `execute = false` no state changes: a `ShardMoveProposed` event is
+    /// emitted for each move so the admin knows which shard accounts to pass
+    /// when executing the batch.
+    pub fn rebalance_shards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RebalanceShards<'info>>,
+        shard_account_count: u8,
+        execute: bool,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::ShardOperator)?;
+
+        ctx.accounts.game_state.require_unpaused(PAUSE_REGISTRATION)?;
+
+        require!(
+            shard_account_count as usize <= ctx.remaining_accounts.len(),
+            TidesError::ArrayLengthMismatch
+        );
+
+        let max_players = ctx.accounts.game_state.max_players_per_shard;
+        let (shard_infos, player_infos) = ctx
+            .remaining_accounts
+            .split_at(shard_account_count as usize);
+
+        // Each copy would be written back on exit, so a repeated account
+        // would silently drop the moves recorded against the other copy
+        let mut keys: Vec<&Pubkey> = ctx.remaining_accounts.iter().map(|info| info.key).collect();
+        keys.sort_unstable();
+        require!(
+            keys.windows(2).all(|pair| pair[0] != pair[1]),
+            TidesError::DuplicateAccount
+        );
+
+        let mut shards = shard_infos
+            .iter()
+            .map(Account::<ShardData>::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        // Plan against a copy so proposals see the effect of earlier moves
+        let mut plan = (*ctx.accounts.shard_directory).clone();
+        let mut moves: u64 = 0;
+
+        for player_info in player_infos {
+            let mut player_state = Account::<PlayerState>::try_from(player_info)?;
+            require!(player_state.is_active, TidesError::PlayerNotRegistered);
+
+            let old_shard = player_state.shard;
+            let new_shard = match plan.rebalance_target(old_shard, max_players) {
+                Some(shard) => shard,
+                None => continue,
+            };
+            plan.player_counts[old_shard as usize] = plan.player_counts[old_shard as usize]
+                .checked_sub(1)
+                .ok_or(TidesError::MathOverflow)?;
+            plan.player_counts[new_shard as usize] = plan.player_counts[new_shard as usize]
+                .checked_add(1)
+                .ok_or(TidesError::MathOverflow)?;
+            moves += 1;
+
+            if !execute {
+                emit!(ShardMoveProposed {
+                    player: player_state.player,
+                    old_shard,
+                    new_shard,
+                });
+                continue;
+            }
+
+            let old_index = shards
+                .iter()
+                .position(|shard| shard.shard_id == old_shard)
+                .ok_or(TidesError::ShardAccountMissing)?;
+            let new_index = shards
+                .iter()
+                .position(|shard| shard.shard_id == new_shard)
+                .ok_or(TidesError::ShardAccountMissing)?;
+
+            let (old_shard_data, new_shard_data) = if old_index < new_index {
+                let (left, right) = shards.split_at_mut(new_index);
+                (&mut left[old_index], &mut right[0])
+            } else {
+                let (left, right) = shards.split_at_mut(old_index);
+                (&mut right[0], &mut left[new_index])
+            };
+
+            ctx.accounts.shard_directory.transfer_player(
+                &mut player_state,
+                old_shard_data,
+                new_shard_data,
+                max_players,
+            )?;
+            player_state.exit(&crate::ID)?;
+
+            emit!(ShardChanged {
+                player: player_state.player,
+                old_shard,
+                new_shard,
+            });
+        }
+
+        if execute {
+            for shard in shards.iter() {
+                shard.exit(&crate::ID)?;
+            }
+        }
+
+        emit!(ShardsRebalanced { moves, executed: execute });
+
+        Ok(())
+    }
+
+    pub fn create_shard(
+        ctx: Context<CreateShard>,
+        shard: u8,
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::ShardOperator)?;
+
+        require!(shard < MAX_SHARDS, TidesError::InvalidShardId);
+
+        let shard_data = &mut ctx.accounts.shard_data;
+        shard_data.shard_id = shard;
+        shard_data.player_count = 0;
+        shard_data.version = ACCOUNT_VERSION;
+
+        let shard_directory = &mut ctx.accounts.shard_directory;
+        shard_directory.is_open[shard as usize] = true;
+        shard_directory.sync(shard_data);
+
+        emit!(ShardCreated { shard });
+
+        Ok(())
+    }
+
+    /// Replaces the current field of a map. Takes effect on the next move.
+    pub fn set_map_currents(
+        ctx: Context<SetMapCurrents>,
+        currents: [Current; CURRENT_REGIONS],
+    ) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::RegistryCurator)?;
+        currents::validate_currents(&currents)?;
+
+        let map = &mut ctx.accounts.map;
+        map.currents = currents;
+
+        emit!(MapCurrentsUpdated {
+            map: map.key(),
+            map_id: map.map_id,
+            currents,
+        });
+
+        Ok(())
+    }
+
+    // ============ Migrations ============
+
+    /// Upgrades an account written by an older program version to the
+    /// current layout, growing first-release accounts to their current
+    /// size. Only a SuperAdmin can migrate; `payer` covers the extra rent.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.payer.key(), Role::SuperAdmin)?;
+        // First-release shards also have to be opened, which migrate_shard does
+        require!(
+            !migrations::is_v0_shard(&ctx.accounts.account)?,
+            TidesError::InvalidAddress
+        );
+
+        migrations::migrate_account(
+            &ctx.accounts.account,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+
+        emit!(AccountMigrated {
+            account: ctx.accounts.account.key(),
+            version: ACCOUNT_VERSION,
+        });
+        Ok(())
+    }
+
+    /// Moves the game state of a game deployed by the first release, a
+    /// keypair account, to the game state PDA and creates the accounts
+    /// `initialize` now creates alongside it, with the first-release admin as
+    /// the only SuperAdmin. The legacy account is closed to the admin.
+    /// First-release players are upgraded with `migrate_account`, which
+    /// links them to the PDA. Shards the release created are passed in
+    /// `remaining_accounts` and opened in the shard directory with their
+    /// player counts; any left out can be brought in with `migrate_shard`.
+    pub fn migrate_game<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateGame<'info>>,
+    ) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_game_state.to_account_info();
+        let legacy = migrations::legacy_game_state(&legacy_info)?;
+        require!(ctx.accounts.admin.key() == legacy.admin, TidesError::MissingRole);
+
+        let admin = legacy.admin;
+        let server_signer = legacy.server_signer;
+        ctx.accounts.game_state.set_inner(legacy);
+
+        // Close the legacy account so it can't pass for a game state again
+        let admin_info = ctx.accounts.admin.to_account_info();
+        **admin_info.try_borrow_mut_lamports()? = admin_info
+            .lamports()
+            .checked_add(legacy_info.lamports())
+            .ok_or(TidesError::MathOverflow)?;
+        **legacy_info.try_borrow_mut_lamports()? = 0;
+        legacy_info.realloc(0, false)?;
+        legacy_info.assign(&system_program::ID);
+        emit!(GameStateMigrated {
+            legacy_game_state: legacy_info.key(),
+            game_state: ctx.accounts.game_state.key(),
+            version: ACCOUNT_VERSION,
+        });
+
+        init_game_accounts(
+            &mut ctx.accounts.shard_directory,
+            &mut ctx.accounts.access_control,
+            &mut ctx.accounts.oracle_set,
+            admin,
+            server_signer,
+        )?;
+
+        let system_program_info = ctx.accounts.system_program.to_account_info();
+        for shard_info in ctx.remaining_accounts {
+            open_legacy_shard(
+                shard_info,
+                &mut ctx.accounts.shard_directory,
+                &admin_info,
+                &system_program_info,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Upgrades a shard created by the first release and opens it in the
+    /// shard directory with its player count. Its PDA already exists, so
+    /// `create_shard` can't be used for it.
+    pub fn migrate_shard(ctx: Context<MigrateShard>) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.payer.key(), Role::SuperAdmin)?;
+
+        open_legacy_shard(
+            &ctx.accounts.shard_data,
+            &mut ctx.accounts.shard_directory,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )
+    }
+
+    /// Stamps the current version on a leaderboard written by an older
+    /// program version. Its layout is unchanged, so no realloc is needed.
+    pub fn migrate_leaderboard(ctx: Context<MigrateLeaderboard>) -> Result<()> {
+        ctx.accounts.access_control.require_role(&ctx.accounts.admin.key(), Role::SuperAdmin)?;
+
+        migrations::migrate_leaderboard(&ctx.accounts.leaderboard)?;
+
+        emit!(AccountMigrated {
+            account: ctx.accounts.leaderboard.key(),
+            version: ACCOUNT_VERSION,
+        });
+        Ok(())
+    }
+
+    // ============ Helper Functions ============
+
+    /// Sets up the singletons created with the game state. The admin starts
+    /// out as the only SuperAdmin and the server signer as the only oracle.
+    fn init_game_accounts(
+        shard_directory: &mut Account<ShardDirectory>,
+        roles: &mut Account<access_control::AccessControl>,
+        oracle_set: &mut Account<fishing::OracleSet>,
+        admin: Pubkey,
+        server_signer: Pubkey,
+    ) -> Result<()> {
+        shard_directory.player_counts = [0; SHARD_DIRECTORY_SIZE];
+        shard_directory.is_open = [false; SHARD_DIRECTORY_SIZE];
+        shard_directory.version = ACCOUNT_VERSION;
+
+        roles.members = Vec::new();
+        roles.version = ACCOUNT_VERSION;
+        access_control::grant_role(roles, admin, Role::SuperAdmin)?;
+
+        let clock = Clock::get()?;
+        oracle_set.signers = Vec::new();
+        oracle_set.cosign_min_base_price = 0;
+        oracle_set.cosign_required = 1;
+        oracle_set.version = ACCOUNT_VERSION;
+        fishing::add_oracle_signer(oracle_set, server_signer, clock.unix_timestamp)
+    }
+
+    /// Upgrades a first-release shard at its PDA and opens it in the
+    /// directory.
+    fn open_legacy_shard<'info>(
+        shard_info: &AccountInfo<'info>,
+        shard_directory: &mut Account<ShardDirectory>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        require!(migrations::is_v0_shard(shard_info)?, TidesError::NotLegacyAccount);
+        migrations::migrate_account(shard_info, payer, system_program)?;
+
+        let shard_data = ShardData::try_deserialize(&mut &shard_info.try_borrow_data()?[..])?;
+        require!(shard_data.shard_id < MAX_SHARDS, TidesError::InvalidShardId);
+        require!(
+            shard_info.key() == ShardData::address(shard_data.shard_id),
+            TidesError::InvalidShardId
+        );
+        shard_directory.is_open[shard_data.shard_id as usize] = true;
+        shard_directory.sync(&shard_data);
+
+        emit!(AccountMigrated {
+            account: shard_info.key(),
+            version: ACCOUNT_VERSION,
+        });
+        Ok(())
+    }
+
+    fn calculate_fish_freshness(caught_at: i64, current_time: i64) -> u64 {
+        let seconds_elapsed = current_time
+            .checked_sub(caught_at)
+            .unwrap_or(0);
+        
+        if seconds_elapsed < 0 {
+            return 100;
+        }
+
+        let decay_periods = seconds_elapsed
+            .checked_div(FRESHNESS_DECAY_PERIOD)
+            .unwrap_or(0);
+        
+        let freshness_decayed = (decay_periods as u64)
+            .checked_mul(FRESHNESS_DECAY_RATE)
+            .unwrap_or(100);
+
+        if freshness_decayed >= 100 {
+            return 0;
+        }
+
+        100 - freshness_decayed
+    }
+
+    fn update_fish_market_data(
+        market_data: &mut Account<FishMarketData>,
+        species: u64,
+        weight: u16,
+        freshness: u64,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let base_price = 100_000_000; // Simplified - would come from fish registry
+        
+        let sale_price = if market_data.last_sold_timestamp == 0 {
+            // First sale
+            base_price
+                .checked_mul(weight as u64)
+                .and_then(|p| p.checked_mul(freshness))
+                .and_then(|p| p.checked_div(100))
+                .ok_or(TidesError::MathOverflow)?;
+            
+            market_data.value = base_price
+                .checked_sub(base_price.checked_mul(PRICE_DECAY_RATE).and_then(|p| p.checked_div(100)).unwrap_or(0))
+                .unwrap_or(0);
+            
+            base_price
+                .checked_mul(weight as u64)
+                .and_then(|p| p.checked_mul(freshness))
+                .and_then(|p| p.checked_div(100))
+                .ok_or(TidesError::MathOverflow)?
+        } else {
+            // Calculate market recovery
+            let seconds_elapsed = current_timestamp
+                .checked_sub(market_data.last_sold_timestamp)
+                .unwrap_or(0);
+            
+            let market_value = market_data.value
+                .checked_add(base_price.checked_mul(PRICE_RECOVERY_RATE).and_then(|p| p.checked_mul(seconds_elapsed as u64)).and_then(|p| p.checked_div(10_000_000)).unwrap_or(0))
+                .unwrap_or(market_data.value);
+            
+            let capped_value = market_value.min(base_price);
+            
+            let sale_price = capped_value
+                .checked_mul(weight as u64)
+                .and_then(|p| p.checked_mul(freshness))
+                .and_then(|p| p.checked_div(100))
+                .ok_or(TidesError::MathOverflow)?;
+            
+            market_data.value = capped_value
+                .checked_sub(capped_value.checked_mul(PRICE_DECAY_RATE).and_then(|p| p.checked_div(100)).unwrap_or(0))
+                .unwrap_or(0);
+            
+            sale_price
+        };
+
+        market_data.last_sold_timestamp = current_timestamp;
+        market_data.species = species;
+
+        Ok(sale_price)
+    }
+}
+
+// ============ Contexts ============
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = GameState::LEN,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = ShardDirectory::LEN,
+        seeds = [b"shard_directory"],
+        bump
+    )]
+    pub shard_directory: Account<'info, ShardDirectory>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = access_control::AccessControl::LEN,
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = fishing::OracleSet::LEN,
+        seeds = [b"oracle_set"],
+        bump
+    )]
+    pub oracle_set: Account<'info, fishing::OracleSet>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterPlayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = PlayerState::LEN,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = fishing::FishingState::LEN,
+        seeds = [b"fishing_state", player.key().as_ref()],
+        bump
+    )]
+    pub fishing_state: Account<'info, fishing::FishingState>,
+    
+    #[account(
+        has_one = game_state @ TidesError::GameMismatch
+    )]
+    pub ship_registry: Account<'info, registries::ShipRegistry>,
+    
+    /// The ship every player starts with, which sizes their inventory
+    #[account(
+        constraint = ship.registry == ship_registry.key() @ TidesError::InvalidShip,
+        constraint = ship.ship_id == STARTING_SHIP_ID @ TidesError::InvalidShip
+    )]
+    pub ship: Account<'info, registries::Ship>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = inventory::PlayerInventory::calculate_size(ship.cargo_width, ship.cargo_height),
+        seeds = [b"inventory", player.key().as_ref()],
+        bump
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    // Only `create_shard` can create `ShardData`, so the account is checked
+    // against the selected shard by `shard_id` in the handler.
+    #[account(mut)]
+    pub shard_data: Account<'info, ShardData>,
+    
+    #[account(
+        mut,
+        seeds = [b"shard_directory"],
+        bump
+    )]
+    pub shard_directory: Account<'info, ShardDirectory>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DeregisterPlayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer,
+        close = player
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        mut,
+        seeds = [b"fishing_state", player.key().as_ref()],
+        bump,
+        has_one = player @ TidesError::InvalidPlayer,
+        close = player
+    )]
+    pub fishing_state: Account<'info, fishing::FishingState>,
+    
+    #[account(
+        mut,
+        seeds = [b"inventory", player.key().as_ref()],
+        bump,
+        has_one = player @ TidesError::InvalidPlayer,
+        close = player
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    #[account(
+        mut,
+        seeds = [b"shard", &[player_state.shard]],
+        bump
+    )]
+    pub shard_data: Account<'info, ShardData>,
+    
+    #[account(
+        mut,
+        seeds = [b"shard_directory"],
+        bump
+    )]
+    pub shard_directory: Account<'info, ShardDirectory>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    /// CHECK: May not exist; deserialized in the handler only when it holds data.
+    #[account(
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the last ended season; may not exist and is
+    /// deserialized in the handler only when it holds data.
+    #[account(
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.last_ended_season_id.to_le_bytes()],
+        bump
+    )]
+    pub ended_season_stats: UncheckedAccount<'info>,
+    
+    pub ended_season: Option<Account<'info, season_pass::Season>>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MovePlayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        has_one = game_state @ TidesError::GameMismatch
+    )]
+    pub map_registry: Account<'info, registries::MapRegistry>,
+    
+    /// The player's current map, whose currents price each step
+    #[account(
+        constraint = map.registry == map_registry.key() @ TidesError::InvalidMap
+    )]
+    pub map: Account<'info, registries::Map>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_stats: Option<Account<'info, season_pass::PlayerSeasonStats>>,
+    
+    pub season_track: Option<Account<'info, season_pass::SeasonTrack>>,
+    
+    /// CHECK: The player's wallet, matched against the player's accounts;
+    /// signs itself or through `session_token`
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player's wallet or their session key
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"session", player.key().as_ref()],
+        bump = session_token.bump
+    )]
+    pub session_token: Option<Account<'info, session::SessionToken>>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseFuel<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    /// CHECK: The player's wallet, matched against the player's accounts;
+    /// signs itself or through `session_token`
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player's wallet or their session key
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", player.key().as_ref()],
+        bump = session_token.bump
+    )]
+    pub session_token: Option<Account<'info, session::SessionToken>>,
+    
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = player
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    /// Game treasury, owned by the game state PDA
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = game_state
+    )]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SellFish<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    pub season_track: Option<Account<'info, season_pass::SeasonTrack>>,
+    
+    #[account(mut)]
+    pub fish_market: Account<'info, FishMarketData>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer,
+        close = player
+    )]
+    pub fish_catch: Account<'info, inventory::FishCatch>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TravelToMap<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    #[account(
+        has_one = game_state @ TidesError::GameMismatch
+    )]
+    pub map_registry: Account<'info, registries::MapRegistry>,
+    
+    #[account(
+        constraint = map.registry == map_registry.key() @ TidesError::InvalidMap
+    )]
+    pub map: Account<'info, registries::Map>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeShip<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    pub ship: Account<'info, registries::Ship>,
+    
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseShip<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    pub ship: Account<'info, registries::Ship>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseEngine<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    pub engine: Account<'info, registries::Engine>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseFishingRod<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    pub fishing_rod: Account<'info, registries::FishingRod>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitiateFishing<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub fishing_state: Account<'info, fishing::FishingState>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_bait: Account<'info, fishing::PlayerBait>,
+    
+    /// CHECK: The player's wallet, matched against the player's accounts;
+    /// signs itself or through `session_token`
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player's wallet or their session key
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"session", player.key().as_ref()],
+        bump = session_token.bump
+    )]
+    pub session_token: Option<Account<'info, session::SessionToken>>,
+}
+
+#[derive(Accounts)]
+#[instruction(species: u64)]
+pub struct FulfillFishing<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        seeds = [b"oracle_set"],
+        bump
+    )]
+    pub oracle_set: Account<'info, fishing::OracleSet>,
+    
+    #[account(
+        has_one = game_state @ TidesError::GameMismatch
+    )]
+    pub fish_registry: Account<'info, registries::FishRegistry>,
+    
+    /// The caught species, required unless the result is no catch
+    #[account(
+        seeds = [b"fish_species", fish_registry.key().as_ref(), &species.to_le_bytes()],
+        bump
+    )]
+    pub fish_species: Option<Account<'info, registries::FishSpecies>>,
+    
+    /// CHECK: Instructions sysvar, read for the Ed25519 signature checks
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_stats: Option<Account<'info, season_pass::PlayerSeasonStats>>,
+    
+    pub season_track: Option<Account<'info, season_pass::SeasonTrack>>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub fishing_state: Account<'info, fishing::FishingState>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = inventory::FishCatch::LEN,
+        seeds = [b"fish", player.key().as_ref(), &fishing_state.fishing_nonce.to_le_bytes()],
+        bump
+    )]
+    pub fish_catch: Account<'info, inventory::FishCatch>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseBait<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    pub bait_type: Account<'info, registries::BaitType>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = fishing::PlayerBait::LEN,
+        seeds = [b"bait", player.key().as_ref(), &bait_type.bait_id.to_le_bytes()],
+        bump
+    )]
+    pub player_bait: Account<'info, fishing::PlayerBait>,
+    
+    /// CHECK: The player's wallet, matched against the player's accounts;
+    /// signs itself or through `session_token`
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player's wallet or their session key, pays for new bait accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", player.key().as_ref()],
+        bump = session_token.bump
+    )]
+    pub session_token: Option<Account<'info, session::SessionToken>>,
+    
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = player
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    /// Game treasury, owned by the game state PDA
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = game_state
+    )]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateInventoryItem<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    /// CHECK: The player's wallet, matched against the player's accounts;
+    /// signs itself or through `session_token`
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player's wallet or their session key
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"session", player.key().as_ref()],
+        bump = session_token.bump
+    )]
+    pub session_token: Option<Account<'info, session::SessionToken>>,
+}
+
+#[derive(Accounts)]
+pub struct CreateSession<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = session::SessionToken::LEN,
+        seeds = [b"session", player.key().as_ref()],
+        bump
+    )]
+    pub session_token: Account<'info, session::SessionToken>,
+    
+    #[account(
+        mut,
+        token::authority = player
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", player.key().as_ref()],
+        bump = session_token.bump,
+        has_one = player @ TidesError::InvalidPlayer,
+        close = player
+    )]
+    pub session_token: Account<'info, session::SessionToken>,
+    
+    #[account(
+        mut,
+        token::authority = player
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DiscardInventoryItem<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    // Required when the discarded item is a fish
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub fish_catch: Option<Account<'info, inventory::FishCatch>>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteActions<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        has_one = game_state @ TidesError::GameMismatch
+    )]
+    pub map_registry: Account<'info, registries::MapRegistry>,
+    
+    /// The player's current map, whose currents price each step
+    #[account(
+        constraint = map.registry == map_registry.key() @ TidesError::InvalidMap
+    )]
+    pub map: Account<'info, registries::Map>,
+    
+    #[account(
+        mut,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    pub season: Option<Account<'info, season_pass::Season>>,
+    
+    /// CHECK: Stats for the current season, which may not exist; loaded in the handler only when they do.
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_pass_state.current_season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: UncheckedAccount<'info>,
+    
+    pub season_track: Option<Account<'info, season_pass::SeasonTrack>>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    /// CHECK: The player's wallet, matched against the player's accounts;
+    /// signs itself or through `session_token`. Receives the rent of
+    /// discarded fish catches.
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player's wallet or their session key
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", player.key().as_ref()],
+        bump = session_token.bump
+    )]
+    pub session_token: Option<Account<'info, session::SessionToken>>,
+    
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = player
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    /// Game treasury, owned by the game state PDA
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = game_state
+    )]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SweepFishCatches<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_shard: u8)]
+pub struct ChangeShard<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        has_one = game_state @ TidesError::GameMismatch
+    )]
+    pub map_registry: Account<'info, registries::MapRegistry>,
+    
+    #[account(
+        constraint = map.registry == map_registry.key() @ TidesError::InvalidMap
+    )]
+    pub map: Account<'info, registries::Map>,
+    
+    #[account(
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub fishing_state: Account<'info, fishing::FishingState>,
+    
+    #[account(
+        mut,
+        seeds = [b"shard", &[player_state.shard]],
+        bump
+    )]
+    pub old_shard_data: Account<'info, ShardData>,
+    
+    #[account(
+        mut,
+        seeds = [b"shard", &[new_shard]],
+        bump
+    )]
+    pub new_shard_data: Account<'info, ShardData>,
+    
+    #[account(
+        mut,
+        seeds = [b"shard_directory"],
+        bump
+    )]
+    pub shard_directory: Account<'info, ShardDirectory>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    /// Game treasury, owned by the game state PDA
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = game_state
+    )]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseSeasonPass<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = season_pass::PlayerSeasonStats::LEN,
+        seeds = [b"season_stats", player.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: Account<'info, season_pass::PlayerSeasonStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_vault", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_vault: Account<'info, season_pass::SeasonVault>,
+    
+    #[account(
+        init,
+        payer = player,
+        seeds = [b"pass_mint".as_ref(), season.collection_mint.as_ref(), &season.total_passes.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = season
+    )]
+    pub pass_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = player,
+        associated_token::mint = pass_mint,
+        associated_token::authority = player
+    )]
+    pub pass_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseSeasonPassWithTokens<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = season_pass::PlayerSeasonStats::LEN,
+        seeds = [b"season_stats", player.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: Account<'info, season_pass::PlayerSeasonStats>,
+    
+    #[account(
+        init,
+        payer = player,
+        seeds = [b"pass_mint".as_ref(), season.collection_mint.as_ref(), &season.total_passes.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = season
+    )]
+    pub pass_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = player,
+        associated_token::mint = pass_mint,
+        associated_token::authority = player
+    )]
+    pub pass_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = player_token_account.mint == game_state.currency_mint @ TidesError::InvalidAddress
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    /// Game treasury, owned by the game state PDA
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = game_state
+    )]
+    pub game_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPass<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_stats", previous_stats.player.as_ref(), &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub previous_stats: Account<'info, season_pass::PlayerSeasonStats>,
+    
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = season_pass::PlayerSeasonStats::LEN,
+        seeds = [b"season_stats", holder.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub holder_stats: Account<'info, season_pass::PlayerSeasonStats>,
+    
+    pub pass_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SyncSeasonPass<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_stats", player_stats.player.as_ref(), &player_stats.season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: Account<'info, season_pass::PlayerSeasonStats>,
+    
+    #[account(
+        seeds = [b"season", &player_stats.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, season_pass::SeasonLeaderboard>>,
+    
+    pub pass_token_account: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSeasonPass<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = season_pass::SeasonPassState::LEN,
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u64, name: String)]
+pub struct CreateSeason<'info> {
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 32 + 1 + 8 + 32
+        space = season_pass::Season::calculate_size(name.len()),
+        seeds = [b"season".as_ref(), &season_id.to_le_bytes()],
+        bump
     )]
-    pub game_state: Account<'info, GameState>,
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = season_pass::SeasonVault::LEN,
+        seeds = [b"season_vault".as_ref(), &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_vault: Account<'info, season_pass::SeasonVault>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"season_collection".as_ref(), &season_id.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = season
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
     #[account(mut)]
     pub admin: Signer<'info>,
     
+    pub token_program: Program<'info, Token>,
+    
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RegisterPlayer<'info> {
-    #[account(mut)]
-    pub game_state: Account<'info, GameState>,
+pub struct SeasonAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
     
     #[account(
-        init,
-        payer = player,
-        space = PlayerState::LEN
+        mut,
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
     )]
-    pub player_state: Account<'info, PlayerState>,
+    pub season: Account<'info, season_pass::Season>,
     
     #[account(
-        init,
-        payer = player,
-        space = ShardData::LEN,
-        seeds = [b"shard", &[shard]],
+        seeds = [b"access_control"],
         bump
     )]
-    pub shard_data: Account<'info, ShardData>,
+    pub access_control: Account<'info, access_control::AccessControl>,
     
-    #[account(mut)]
-    pub player: Signer<'info>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
     
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(zero)]
+    pub leaderboard: AccountLoader<'info, season_pass::SeasonLeaderboard>,
+    
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct MovePlayer<'info> {
-    #[account(mut)]
-    pub game_state: Account<'info, GameState>,
+pub struct FinalizeSeason<'info> {
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
     
     #[account(
         mut,
-        has_one = player @ TidesError::InvalidPlayer
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump,
+        has_one = leaderboard @ TidesError::InvalidAddress
     )]
-    pub player_state: Account<'info, PlayerState>,
+    pub season: Account<'info, season_pass::Season>,
     
-    pub player: Signer<'info>,
+    pub leaderboard: AccountLoader<'info, season_pass::SeasonLeaderboard>,
+    
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseFuel<'info> {
-    #[account(mut)]
+pub struct ClaimSeasonReward<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
     pub game_state: Account<'info, GameState>,
     
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
+    #[account(
+        mut,
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump,
+        has_one = leaderboard @ TidesError::InvalidAddress
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_vault", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_vault: Account<'info, season_pass::SeasonVault>,
+    
+    pub leaderboard: AccountLoader<'info, season_pass::SeasonLeaderboard>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_stats: Account<'info, season_pass::PlayerSeasonStats>,
     
     #[account(mut)]
     pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepUnclaimedRewards<'info> {
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump,
+        has_one = treasury @ TidesError::InvalidAddress
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
     
-    #[account(mut)]
-    pub player_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_vault", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_vault: Account<'info, season_pass::SeasonVault>,
     
     #[account(mut)]
-    pub game_token_account: Account<'info, TokenAccount>,
+    pub treasury: SystemAccount<'info>,
     
-    pub token_program: Program<'info, Token>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct SellFish<'info> {
-    #[account(mut)]
-    pub game_state: Account<'info, GameState>,
+pub struct WithdrawSeasonRevenue<'info> {
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump,
+        has_one = treasury @ TidesError::InvalidAddress
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
     
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
+    #[account(
+        mut,
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_vault", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_vault: Account<'info, season_pass::SeasonVault>,
     
     #[account(mut)]
-    pub fish_market: Account<'info, FishMarketData>,
+    pub treasury: SystemAccount<'info>,
     
-    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct TravelToMap<'info> {
+pub struct CreateSeasonTrack<'info> {
+    #[account(
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    
+    #[account(
+        seeds = [b"season", &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = season_pass::SeasonTrack::LEN,
+        seeds = [b"season_track".as_ref(), &season.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_track: Account<'info, season_pass::SeasonTrack>,
+    
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
     #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct JoinSeason<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
     pub game_state: Account<'info, GameState>,
     
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
+    #[account(
+        seeds = [b"season", &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
     
-    pub map: Account<'info, registries::Map>,
+    #[account(
+        init,
+        payer = player,
+        space = season_pass::PlayerSeasonStats::LEN,
+        seeds = [b"season_stats".as_ref(), player.key().as_ref(), &season_id.to_le_bytes()],
+        bump
+    )]
+    pub player_stats: Account<'info, season_pass::PlayerSeasonStats>,
     
     #[account(mut)]
     pub player: Signer<'info>,
     
-    #[account(mut)]
-    pub player_token_account: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u64, tier: u8, premium: bool)]
+pub struct ClaimTierReward<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        seeds = [b"season", &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, season_pass::Season>,
+    
+    #[account(
+        seeds = [b"season_track", &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_track: Account<'info, season_pass::SeasonTrack>,
+    
+    #[account(
+        mut,
+        seeds = [b"season_stats", player.key().as_ref(), &season_id.to_le_bytes()],
+        bump,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_stats: Account<'info, season_pass::PlayerSeasonStats>,
+    
+    /// Required for premium rewards, holding the player's pass
+    pub pass_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Required for fuel rewards
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch,
+        has_one = player @ TidesError::InvalidPlayer
+    )]
+    pub player_state: Option<Account<'info, PlayerState>>,
+    
+    /// Required for bait rewards
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = fishing::PlayerBait::LEN,
+        seeds = [
+            b"bait".as_ref(),
+            player.key().as_ref(),
+            &season_track.reward(tier, premium).item_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub player_bait: Option<Account<'info, fishing::PlayerBait>>,
+    
+    /// Required for currency rewards, funded by the admin
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = season_track
+    )]
+    pub reward_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = game_state.currency_mint,
+        token::authority = player
+    )]
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
     
     #[account(mut)]
-    pub game_token_account: Account<'info, TokenAccount>,
+    pub player: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ChangeShip<'info> {
-    #[account(mut)]
-    pub game_state: Account<'info, GameState>,
-    
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
-    
-    pub ship: Account<'info, registries::Ship>,
-    
-    pub player: Signer<'info>,
+pub struct GetPlayerPosition<'info> {
+    pub leaderboard: AccountLoader<'info, season_pass::SeasonLeaderboard>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseShip<'info> {
-    #[account(mut)]
-    pub game_state: Account<'info, GameState>,
-    
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
-    
-    pub ship: Account<'info, registries::Ship>,
-    
-    #[account(mut)]
-    pub player: Signer<'info>,
+pub struct ManageRoles<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    /// CHECK: GameState, SeasonPassState or a registry owned by this
+    /// program; `apply_admin_op` checks the owner and discriminator.
     #[account(mut)]
-    pub player_token_account: Account<'info, TokenAccount>,
+    pub target: UncheckedAccount<'info>,
     
-    #[account(mut)]
-    pub game_token_account: Account<'info, TokenAccount>,
+    /// Checked when proposing a new game admin
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
-    pub token_program: Program<'info, Token>,
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseEngine<'info> {
-    #[account(mut)]
+pub struct ProposeChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
     pub game_state: Account<'info, GameState>,
     
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
-    
-    pub engine: Account<'info, registries::Engine>,
-    
-    #[account(mut)]
-    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = governance::PendingChange::LEN,
+        seeds = [b"pending_change".as_ref(), &game_state.next_change_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_change: Account<'info, governance::PendingChange>,
     
-    #[account(mut)]
-    pub player_token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
     #[account(mut)]
-    pub game_token_account: Account<'info, TokenAccount>,
+    pub admin: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseFishingRod<'info> {
-    #[account(mut)]
+pub struct ExecuteChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
     pub game_state: Account<'info, GameState>,
     
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
-    
-    pub fishing_rod: Account<'info, registries::FishingRod>,
+    #[account(
+        mut,
+        seeds = [b"pending_change", &pending_change.change_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_change: Account<'info, governance::PendingChange>,
     
-    #[account(mut)]
-    pub player: Signer<'info>,
+    /// Required for treasury changes
+    #[account(
+        mut,
+        seeds = [b"season_pass_state"],
+        bump
+    )]
+    pub season_pass_state: Option<Account<'info, season_pass::SeasonPassState>>,
     
-    #[account(mut)]
-    pub player_token_account: Account<'info, TokenAccount>,
+    /// Required for oracle signer and co-signing changes
+    #[account(
+        mut,
+        seeds = [b"oracle_set"],
+        bump
+    )]
+    pub oracle_set: Option<Account<'info, fishing::OracleSet>>,
     
-    #[account(mut)]
-    pub game_token_account: Account<'info, TokenAccount>,
+    /// Required for SuperAdmin and Guardian changes
+    #[account(
+        mut,
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Option<Account<'info, access_control::AccessControl>>,
     
-    pub token_program: Program<'info, Token>,
+    pub executor: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct InitiateFishing<'info> {
-    #[account(mut)]
-    pub game_state: Account<'info, GameState>,
+pub struct VetoChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending_change", &pending_change.change_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_change: Account<'info, governance::PendingChange>,
     
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdminOnly<'info> {
     #[account(
         mut,
-        has_one = player @ TidesError::InvalidPlayer
+        seeds = [b"game"],
+        bump
     )]
-    pub fishing_state: Account<'info, fishing::FishingState>,
+    pub game_state: Account<'info, GameState>,
     
     #[account(
-        mut,
-        has_one = player @ TidesError::InvalidPlayer
+        seeds = [b"access_control"],
+        bump
     )]
-    pub player_bait: Account<'info, fishing::PlayerBait>,
+    pub access_control: Account<'info, access_control::AccessControl>,
     
-    pub player: Signer<'info>,
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct FulfillFishing<'info> {
-    #[account(mut)]
+#[instruction(new_shard: u8)]
+pub struct AdminChangeShard<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump
+    )]
     pub game_state: Account<'info, GameState>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = game_state @ TidesError::GameMismatch
+    )]
     pub player_state: Account<'info, PlayerState>,
     
     #[account(
         mut,
-        has_one = player @ TidesError::InvalidPlayer
+        seeds = [b"shard", &[player_state.shard]],
+        bump
     )]
-    pub fishing_state: Account<'info, fishing::FishingState>,
+    pub old_shard_data: Account<'info, ShardData>,
     
     #[account(
         mut,
-        has_one = player @ TidesError::InvalidPlayer
+        seeds = [b"shard", &[new_shard]],
+        bump
     )]
-    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    pub new_shard_data: Account<'info, ShardData>,
     
     #[account(
-        init,
-        payer = player,
-        space = inventory::FishCatch::LEN,
-        seeds = [b"fish", player.key().as_ref(), &fishing_state.fishing_nonce.to_le_bytes()],
+        mut,
+        seeds = [b"shard_directory"],
         bump
     )]
-    pub fish_catch: Account<'info, inventory::FishCatch>,
+    pub shard_directory: Account<'info, ShardDirectory>,
     
-    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
-    pub system_program: Program<'info, System>,
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseBait<'info> {
-    #[account(mut)]
+pub struct RebalanceShards<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
     pub game_state: Account<'info, GameState>,
     
-    #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
+    #[account(
+        mut,
+        seeds = [b"shard_directory"],
+        bump
+    )]
+    pub shard_directory: Account<'info, ShardDirectory>,
     
-    pub bait_type: Account<'info, registries::BaitType>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(shard: u8)]
+pub struct CreateShard<'info> {
     #[account(
-        init_if_needed,
-        payer = player,
-        space = fishing::PlayerBait::LEN,
-        seeds = [b"bait", player.key().as_ref(), &bait_type.bait_id.to_le_bytes()],
+        seeds = [b"game"],
         bump
     )]
-    pub player_bait: Account<'info, fishing::PlayerBait>,
+    pub game_state: Account<'info, GameState>,
     
-    #[account(mut)]
-    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = ShardData::LEN,
+        seeds = [b"shard".as_ref(), &[shard]],
+        bump
+    )]
+    pub shard_data: Account<'info, ShardData>,
     
-    #[account(mut)]
-    pub player_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"shard_directory"],
+        bump
+    )]
+    pub shard_directory: Account<'info, ShardDirectory>,
     
-    #[account(mut)]
-    pub game_token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
     
-    pub token_program: Program<'info, Token>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateInventoryItem<'info> {
-    #[account(mut)]
+pub struct SetMapCurrents<'info> {
+    #[account(
+        seeds = [b"game"],
+        bump
+    )]
     pub game_state: Account<'info, GameState>,
     
+    #[account(
+        has_one = game_state @ TidesError::GameMismatch
+    )]
+    pub map_registry: Account<'info, registries::MapRegistry>,
+    
     #[account(
         mut,
-        has_one = player @ TidesError::InvalidPlayer
+        constraint = map.registry == map_registry.key() @ TidesError::InvalidMap
     )]
-    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    pub map: Account<'info, registries::Map>,
     
-    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"access_control"],
+        bump
+    )]
+    pub access_control: Account<'info, access_control::AccessControl>,
+    
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct DiscardInventoryItem<'info> {
+pub struct MigrateAccount<'info> {
+    /// CHECK: Any Borsh account owned by this program; `migrate_account`
+    /// checks the owner and discriminator.
     #[account(mut)]
-    pub game_state: Account<'info, GameState>,
+    pub account: UncheckedAccount<'info>,
     
     #[account(
-        mut,
-        has_one = player @ TidesError::InvalidPlayer
+        seeds = [b"access_control"],
+        bump
     )]
-    pub player_inventory: Account<'info, inventory::PlayerInventory>,
+    pub access_control: Account<'info, access_control::AccessControl>,
     
-    pub player: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseSeasonPass<'info> {
-    #[account(mut)]
-    pub game_state: Account<'info, GameState>,
+pub struct MigrateGame<'info> {
+    /// CHECK: First-release game state, a keypair account; `migrate_game`
+    /// parses its layout, checks the admin and closes it.
+    #[account(
+        mut,
+        owner = crate::ID @ TidesError::InvalidAddress
+    )]
+    pub legacy_game_state: UncheckedAccount<'info>,
     
-    #[account(mut)]
-    pub season: Account<'info, season_pass::Season>,
+    #[account(
+        init,
+        payer = admin,
+        space = GameState::LEN,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
     
     #[account(
-        init_if_needed,
-        payer = player,
-        space = season_pass::PlayerSeasonStats::LEN,
-        seeds = [b"season_stats", player.key().as_ref(), &season.season_id.to_le_bytes()],
+        init,
+        payer = admin,
+        space = ShardDirectory::LEN,
+        seeds = [b"shard_directory"],
+        bump
+    )]
+    pub shard_directory: Account<'info, ShardDirectory>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = access_control::AccessControl::LEN,
+        seeds = [b"access_control"],
         bump
     )]
-    pub player_stats: Account<'info, season_pass::PlayerSeasonStats>,
+    pub access_control: Account<'info, access_control::AccessControl>,
     
     #[account(
         init,
-        payer = player,
-        space = season_pass::SeasonPassState::LEN,
-        seeds = [b"season_pass_state"],
+        payer = admin,
+        space = fishing::OracleSet::LEN,
+        seeds = [b"oracle_set"],
         bump
     )]
-    pub season_pass_state: Account<'info, season_pass::SeasonPassState>,
+    pub oracle_set: Account<'info, fishing::OracleSet>,
     
     #[account(mut)]
-    pub player: Signer<'info>,
+    pub admin: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct AdminOnly<'info> {
+pub struct MigrateShard<'info> {
+    /// CHECK: First-release shard; `migrate_shard` parses its layout and
+    /// checks it sits at the PDA of its shard id.
+    #[account(mut)]
+    pub shard_data: UncheckedAccount<'info>,
+    
     #[account(
         mut,
-        has_one = admin @ TidesError::InvalidAddress
+        seeds = [b"shard_directory"],
+        bump
     )]
-    pub game_state: Account<'info, GameState>,
+    pub shard_directory: Account<'info, ShardDirectory>,
     
-    pub admin: Signer<'info>,
-}
-
-#[derive(Accounts)]
-#[instruction(new_shard: u8)]
-pub struct AdminChangeShard<'info> {
     #[account(
-        mut,
-        has_one = admin @ TidesError::InvalidAddress
+        seeds = [b"access_control"],
+        bump
     )]
-    pub game_state: Account<'info, GameState>,
+    pub access_control: Account<'info, access_control::AccessControl>,
     
     #[account(mut)]
-    pub player_state: Account<'info, PlayerState>,
+    pub payer: Signer<'info>,
     
-    #[account(
-        mut,
-        seeds = [b"shard", &[player_state.shard]],
-        bump
-    )]
-    pub old_shard_data: Account<'info, ShardData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateLeaderboard<'info> {
+    /// CHECK: Zero-copy leaderboard owned by this program;
+    /// `migrate_leaderboard` checks the owner and discriminator.
+    #[account(mut)]
+    pub leaderboard: UncheckedAccount<'info>,
     
     #[account(
-        init_if_needed,
-        payer = admin,
-        space = ShardData::LEN,
-        seeds = [b"shard", &[new_shard]],
+        seeds = [b"access_control"],
         bump
     )]
-    pub new_shard_data: Account<'info, ShardData>,
+    pub access_control: Account<'info, access_control::AccessControl>,
     
     pub admin: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
 }
 
 // ============ Events ============
 
+#[event]
+pub struct ChangeProposed {
+    pub change_id: u64,
+    pub change: ParameterChange,
+    pub proposer: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct ChangeExecuted {
+    pub change_id: u64,
+    pub change: ParameterChange,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct ChangeVetoed {
+    pub change_id: u64,
+    pub change: ParameterChange,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct SubsystemsPaused {
+    pub flags: u8,
+    pub paused: u8,
+    pub reason_code: u16,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct SubsystemsUnpaused {
+    pub flags: u8,
+    pub paused: u8,
+    pub reason_code: u16,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub account: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub account: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub account: Pubkey,
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub account: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub account: Pubkey,
+    pub role: Role,
+    pub revoked_by: Pubkey,
+}
+
 #[event]
 pub struct PlayerRegistered {
     pub player: Pubkey,
     pub shard: u8,
 }
 
+#[event]
+pub struct PlayerDeregistered {
+    pub player: Pubkey,
+    pub shard: u8,
+}
+
 #[event]
 pub struct PlayerMoved {
     pub player: Pubkey,
@@ -1417,12 +5068,151 @@ pub struct ItemDiscarded {
     pub instance_id: u64,
 }
 
+#[event]
+pub struct FishCatchesSwept {
+    pub player: Pubkey,
+    pub reclaimed: u64,
+}
+
+#[event]
+pub struct SeasonPassTransferred {
+    pub season_id: u64,
+    pub pass_mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+}
+
+#[event]
+pub struct LeaderboardChanged {
+    pub season_id: u64,
+    pub player: Pubkey,
+    pub net_value: i64,
+    pub old_rank: Option<u32>,
+    pub new_rank: u32,
+}
+
+#[event]
+pub struct LeaderboardEntryRemoved {
+    pub season_id: u64,
+    pub player: Pubkey,
+    pub old_rank: u32,
+}
+
+#[event]
+pub struct SeasonFinalized {
+    pub season_id: u64,
+    pub reward_pool: u64,
+    pub reward_count: u32,
+    pub payout_ratio_bps: u16,
+    pub claim_deadline: i64,
+}
+
+#[event]
+pub struct SeasonRewardClaimed {
+    pub season_id: u64,
+    pub player: Pubkey,
+    pub rank: u32,
+    pub amount: u64,
+}
+
+#[event]
+pub struct UnclaimedRewardsSwept {
+    pub season_id: u64,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SeasonRevenueWithdrawn {
+    pub season_id: u64,
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct SeasonTrackCreated {
+    pub season_id: u64,
+    pub tier_count: u8,
+    pub xp_per_catch: u64,
+    pub xp_per_sale: u64,
+    pub xp_per_hex: u64,
+}
+
+#[event]
+pub struct SeasonJoined {
+    pub season_id: u64,
+    pub player: Pubkey,
+}
+
+#[event]
+pub struct SeasonXpEarned {
+    pub season_id: u64,
+    pub player: Pubkey,
+    pub xp: u64,
+    pub total_xp: u64,
+}
+
+#[event]
+pub struct TierRewardClaimed {
+    pub season_id: u64,
+    pub player: Pubkey,
+    pub tier: u8,
+    pub premium: bool,
+    pub kind: season_pass::TierRewardKind,
+    pub item_id: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SeasonCreated {
+    pub season_id: u64,
+    pub name: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub pass_price: u64,
+}
+
+#[event]
+pub struct SeasonActivated {
+    pub season_id: u64,
+}
+
+#[event]
+pub struct SeasonUpdated {
+    pub season_id: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub pass_price: u64,
+    pub token_pass_price: u64,
+    pub prize_pool_bps: u16,
+}
+
+#[event]
+pub struct SeasonEnded {
+    pub season_id: u64,
+    pub total_passes: u64,
+}
+
 #[event]
 pub struct SeasonPassPurchased {
     pub player: Pubkey,
     pub season_id: u64,
 }
 
+#[event]
+pub struct ShardCreated {
+    pub shard: u8,
+}
+
+#[event]
+pub struct MapCurrentsUpdated {
+    pub map: Pubkey,
+    pub map_id: u64,
+    pub currents: [Current; CURRENT_REGIONS],
+}
+
 #[event]
 pub struct ShardChanged {
     pub player: Pubkey,
@@ -1430,11 +5220,66 @@ pub struct ShardChanged {
     pub new_shard: u8,
 }
 
+#[event]
+pub struct ShardMoveProposed {
+    pub player: Pubkey,
+    pub old_shard: u8,
+    pub new_shard: u8,
+}
+
+#[event]
+pub struct ShardsRebalanced {
+    pub moves: u64,
+    pub executed: bool,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct GameStateMigrated {
+    pub legacy_game_state: Pubkey,
+    pub game_state: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub player: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    pub spend_cap: u64,
+    pub allowed_instructions: u16,
+}
+
+#[event]
+pub struct SessionRevoked {
+    pub player: Pubkey,
+    pub session_key: Pubkey,
+    pub spent: u64,
+}
+
+#[event]
+pub struct ActionsExecuted {
+    pub player: Pubkey,
+    pub shard: u8,
+    pub map_id: u64,
+    pub x: i32,
+    pub y: i32,
+    pub fuel_consumed: u64,
+    pub fuel_purchased: u64,
+    pub tokens_spent: u64,
+    pub compute_units: Vec<u64>, // Used by each action, in order
+}
+
 // ============ Errors ============
 
 #[error_code]
 pub enum TidesError {
-    #[msg("Game is paused")]
+    #[msg("Game or subsystem is paused")]
     GamePaused,
     
     #[msg("Player not registered")]
@@ -1589,4 +5434,178 @@ pub enum TidesError {
     
     #[msg("Limit out of bounds")]
     LimitOutOfBounds,
+    
+    #[msg("No open shard available")]
+    NoOpenShard,
+    
+    #[msg("Shard account missing")]
+    ShardAccountMissing,
+    
+    #[msg("Shard change on cooldown")]
+    ShardChangeOnCooldown,
+    
+    #[msg("Season account required")]
+    SeasonAccountRequired,
+    
+    #[msg("Season stats locked for rewards")]
+    SeasonStatsLocked,
+    
+    #[msg("Fish catch account required")]
+    FishCatchRequired,
+    
+    #[msg("Fish catch still in inventory")]
+    FishCatchInUse,
+    
+    #[msg("Season already active")]
+    SeasonAlreadyActive,
+    
+    #[msg("Another season is in progress")]
+    SeasonAlreadyInProgress,
+    
+    #[msg("Season has ended")]
+    SeasonHasEnded,
+    
+    #[msg("Token payment disabled for this season")]
+    TokenPaymentDisabled,
+    
+    #[msg("Season already started")]
+    SeasonAlreadyStarted,
+    
+    #[msg("Leaderboard account required")]
+    LeaderboardRequired,
+    
+    #[msg("Leaderboard already initialized")]
+    LeaderboardAlreadyInitialized,
+    
+    #[msg("Season not ended")]
+    SeasonNotEnded,
+    
+    #[msg("Season already finalized")]
+    SeasonAlreadyFinalized,
+    
+    #[msg("Season not finalized")]
+    SeasonNotFinalized,
+    
+    #[msg("Reward already claimed")]
+    RewardAlreadyClaimed,
+    
+    #[msg("Not eligible for reward")]
+    NotEligibleForReward,
+    
+    #[msg("Claim period over")]
+    ClaimPeriodOver,
+    
+    #[msg("Claim period still active")]
+    ClaimPeriodActive,
+    
+    #[msg("Track tiers must be non-empty, at most 64 and sorted by XP")]
+    InvalidTrackTiers,
+    
+    #[msg("Season track account required")]
+    SeasonTrackRequired,
+    
+    #[msg("Invalid tier")]
+    InvalidTier,
+    
+    #[msg("Not enough season XP for this tier")]
+    TierLocked,
+    
+    #[msg("Tier has no reward in this lane")]
+    NoTierReward,
+    
+    #[msg("Tier reward already claimed")]
+    TierAlreadyClaimed,
+    
+    #[msg("Reward destination account required")]
+    RewardAccountRequired,
+    
+    #[msg("Signer does not hold the required role")]
+    MissingRole,
+    
+    #[msg("Too many role members")]
+    TooManyRoleMembers,
+    
+    #[msg("Cannot revoke the last SuperAdmin")]
+    LastSuperAdmin,
+    
+    #[msg("No admin transfer pending")]
+    NoPendingAdmin,
+    
+    #[msg("Access control account required")]
+    AccessControlRequired,
+    
+    #[msg("Game or subsystem is not paused")]
+    GameNotPaused,
+    
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    
+    #[msg("Season pass state account required")]
+    SeasonPassStateRequired,
+    
+    #[msg("Change is not pending")]
+    ChangeNotPending,
+    
+    #[msg("Timelock has not expired")]
+    TimelockNotExpired,
+    
+    #[msg("Oracle set account required")]
+    OracleSetRequired,
+    
+    #[msg("Oracle signer already in the set")]
+    OracleSignerExists,
+    
+    #[msg("Oracle signer not found")]
+    OracleSignerNotFound,
+    
+    #[msg("At least one oracle signer must stay active")]
+    NoActiveOracleSigner,
+    
+    #[msg("Too many oracle signers")]
+    TooManyOracleSigners,
+    
+    #[msg("Not enough valid oracle signatures")]
+    InsufficientOracleSignatures,
+    
+    #[msg("Fish species account required")]
+    FishSpeciesRequired,
+    
+    #[msg("Account is not in a legacy layout")]
+    NotLegacyAccount,
+    
+    #[msg("Account belongs to a different game")]
+    GameMismatch,
+    
+    #[msg("Invalid session")]
+    InvalidSession,
+    
+    #[msg("Session has expired")]
+    SessionExpired,
+    
+    #[msg("Session does not allow this instruction")]
+    SessionActionNotAllowed,
+    
+    #[msg("Session spend cap exceeded")]
+    SessionSpendCapExceeded,
+    
+    #[msg("Action batch is empty, too long or moves more than once")]
+    InvalidActionBatch,
+    
+    #[msg("No route to the destination")]
+    NoRouteFound,
+    
+    #[msg("Current direction or strength out of range")]
+    InvalidCurrent,
+    
+    #[msg("Change is past its execution window")]
+    ChangeExpired,
+    
+    #[msg("SuperAdmin and Guardian roles change only through propose_change")]
+    RoleRequiresTimelock,
+    
+    #[msg("Account passed more than once")]
+    DuplicateAccount,
+    
+    #[msg("Player still holds the season pass")]
+    PassStillHeld,
 }
@@ -0,0 +1,690 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use crate::{
+    AccessControl, BaitType, Engine, EngineRegistry, FishCatch, FishMarketData, FishRegistry,
+    FishSpecies, FishingRod, FishingRodRegistry, FishingState, GameState, Map, MapRegistry,
+    OracleSet, PendingChange, PlayerBait, PlayerInventory, PlayerSeasonStats, PlayerState, Season,
+    SeasonLeaderboard, SeasonPassState, SeasonTrack, SeasonVault, SessionToken, ShardData,
+    ShardDirectory, Ship, ShipRegistry, TidesError, ACCOUNT_RESERVED_BYTES, ACCOUNT_VERSION,
+    CHANGE_EXECUTION_WINDOW, DEFAULT_TIMELOCK_DELAY, PAUSE_ALL,
+};
+
+// ============ Migration Constants ============
+pub const VERSION_FOOTPRINT: usize = 1 + ACCOUNT_RESERVED_BYTES; // version + _reserved
+pub const LEADERBOARD_VERSION_OFFSET: usize = 8 + 8 + 4; // discriminator + season_id + count
+
+// ============ Versioned Accounts ============
+
+pub trait Versioned {
+    /// Bytes the account needs in the current layout.
+    fn space(&self) -> usize;
+
+    fn version(&self) -> u8;
+
+    /// Fills the fields added since `version()`, stamps the current version
+    /// and clears the reserved bytes.
+    fn upgrade(&mut self);
+
+    /// Parses `data` written by the first release (v0) and brings it to the
+    /// current version. Only the account types that release could create
+    /// have a v0 layout.
+    fn from_v0(_data: &[u8]) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        err!(TidesError::NotLegacyAccount)
+    }
+}
+
+macro_rules! impl_versioned {
+    ($($account:ty => |$this:ident| $space:expr $(, link $game:ident)? $(, then $hook:path)? $(, v0 $legacy:ident)?;)*) => {
+        $(impl Versioned for $account {
+            fn space(&self) -> usize {
+                let $this = self;
+                $space
+            }
+
+            fn version(&self) -> u8 {
+                self.version
+            }
+
+            fn upgrade(&mut self) {
+                // v2: link to the game state PDA
+                $(if self.$game == Pubkey::default() {
+                    self.$game = GameState::address();
+                })?
+                // v3: map currents come from reserved bytes, so start calm
+                // v4: pending changes get an expiry
+                // v5: the season pass state records no ended season until
+                // its next end_season
+                $($hook(self);)?
+                self.version = ACCOUNT_VERSION;
+                self._reserved.fill(0);
+            }
+
+            $(fn from_v0(data: &[u8]) -> Result<Self> {
+                require!(data.len() == v0::$legacy::LEN, TidesError::NotLegacyAccount);
+                require!(
+                    data[..8] == <$account>::DISCRIMINATOR,
+                    ErrorCode::AccountDiscriminatorMismatch
+                );
+                let legacy = v0::$legacy::deserialize(&mut &data[8..])
+                    .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+                let mut account = Self::from(legacy);
+                account.upgrade();
+                Ok(account)
+            })?
+        })*
+    };
+}
+
+impl_versioned! {
+    GameState => |_account| GameState::LEN, v0 GameState;
+    PlayerState => |_account| PlayerState::LEN, link game_state, v0 PlayerState;
+    FishMarketData => |_account| FishMarketData::LEN;
+    ShardData => |_account| ShardData::LEN, v0 ShardData;
+    ShardDirectory => |_account| ShardDirectory::LEN;
+    AccessControl => |_account| AccessControl::LEN;
+    FishingState => |_account| FishingState::LEN;
+    PlayerBait => |_account| PlayerBait::LEN;
+    OracleSet => |_account| OracleSet::LEN;
+    PendingChange => |_account| PendingChange::LEN, then set_change_expiry;
+    PlayerInventory => |inventory| PlayerInventory::calculate_size(inventory.width, inventory.height);
+    FishCatch => |_account| FishCatch::LEN;
+    FishRegistry => |_account| FishRegistry::LEN, link game_state;
+    FishSpecies => |species| FishSpecies::calculate_size(species.shape_data.len());
+    BaitType => |bait| BaitType::calculate_size(bait.name.len());
+    ShipRegistry => |_account| ShipRegistry::LEN, link game_state;
+    Ship => |ship| Ship::calculate_size(ship.cargo_width, ship.cargo_height);
+    EngineRegistry => |_account| EngineRegistry::LEN, link game_state;
+    Engine => |engine| Engine::calculate_size(engine.shape_data.len());
+    FishingRodRegistry => |_account| FishingRodRegistry::LEN, link game_state;
+    FishingRod => |rod| FishingRod::calculate_size(rod.shape_data.len());
+    MapRegistry => |_account| MapRegistry::LEN, link game_state;
+    Map => |map| Map::calculate_size(map.harbors.len());
+    SeasonPassState => |_account| SeasonPassState::LEN;
+    Season => |season| Season::calculate_size(season.name.len());
+    SeasonVault => |_account| SeasonVault::LEN;
+    PlayerSeasonStats => |_account| PlayerSeasonStats::LEN;
+    SeasonTrack => |_account| SeasonTrack::LEN;
+    SessionToken => |_account| SessionToken::LEN;
+}
+
+/// v4: changes queued before they expired get a full execution window
+/// from when they became executable.
+fn set_change_expiry(change: &mut PendingChange) {
+    if change.expires_at == 0 {
+        change.expires_at = change.executable_at.saturating_add(CHANGE_EXECUTION_WINDOW);
+    }
+}
+
+// ============ First Release Layouts ============
+
+/// Accounts as the first release wrote them, before versioning (v0). That
+/// release could only create the game state, players and shards; every
+/// other account type was introduced with a version byte.
+pub mod v0 {
+    use super::*;
+
+    #[derive(AnchorSerialize, AnchorDeserialize)]
+    pub struct GameState {
+        pub currency_mint: Pubkey,
+        pub admin: Pubkey,
+        pub paused: bool,
+        pub max_players_per_shard: u64,
+        pub server_signer: Pubkey,
+    }
+
+    impl GameState {
+        pub const LEN: usize = 8 + // discriminator
+            32 + // currency_mint
+            32 + // admin
+            1 + // paused
+            8 + // max_players_per_shard
+            32; // server_signer
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize)]
+    pub struct PlayerState {
+        pub player: Pubkey,
+        pub map_id: u64,
+        pub ship_id: u64,
+        pub current_fuel: u64,
+        pub last_move_timestamp: i64,
+        pub next_move_time: i64,
+        pub movement_speed: u64,
+        pub position_x: i32,
+        pub position_y: i32,
+        pub shard: u8,
+        pub is_active: bool,
+    }
+
+    impl PlayerState {
+        pub const LEN: usize = 8 + // discriminator
+            32 + // player
+            8 + // map_id
+            8 + // ship_id
+            8 + // current_fuel
+            8 + // last_move_timestamp
+            8 + // next_move_time
+            8 + // movement_speed
+            4 + // position_x
+            4 + // position_y
+            1 + // shard
+            1; // is_active
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize)]
+    pub struct ShardData {
+        pub shard_id: u8,
+        pub player_count: u64,
+    }
+
+    impl ShardData {
+        pub const LEN: usize = 8 + // discriminator
+            1 + // shard_id
+            8; // player_count
+    }
+}
+
+/// A paused v0 game stopped everything, so it maps to every pause flag.
+impl From<v0::GameState> for GameState {
+    fn from(legacy: v0::GameState) -> Self {
+        Self {
+            currency_mint: legacy.currency_mint,
+            admin: legacy.admin,
+            paused: if legacy.paused { PAUSE_ALL } else { 0 },
+            max_players_per_shard: legacy.max_players_per_shard,
+            server_signer: legacy.server_signer,
+            shard_change_fee: 0,
+            pending_admin: Pubkey::default(),
+            timelock_delay: DEFAULT_TIMELOCK_DELAY,
+            next_change_id: 0,
+            version: 0,
+            _reserved: [0; ACCOUNT_RESERVED_BYTES],
+        }
+    }
+}
+
+/// v0 players never changed shard themselves, so they start off cooldown.
+impl From<v0::PlayerState> for PlayerState {
+    fn from(legacy: v0::PlayerState) -> Self {
+        Self {
+            player: legacy.player,
+            map_id: legacy.map_id,
+            ship_id: legacy.ship_id,
+            current_fuel: legacy.current_fuel,
+            last_move_timestamp: legacy.last_move_timestamp,
+            next_move_time: legacy.next_move_time,
+            movement_speed: legacy.movement_speed,
+            position_x: legacy.position_x,
+            position_y: legacy.position_y,
+            shard: legacy.shard,
+            is_active: legacy.is_active,
+            last_shard_change: 0,
+            version: 0,
+            game_state: Pubkey::default(),
+            _reserved: [0; ACCOUNT_RESERVED_BYTES - 32],
+        }
+    }
+}
+
+impl From<v0::ShardData> for ShardData {
+    fn from(legacy: v0::ShardData) -> Self {
+        Self {
+            shard_id: legacy.shard_id,
+            player_count: legacy.player_count,
+            version: 0,
+            _reserved: [0; ACCOUNT_RESERVED_BYTES],
+        }
+    }
+}
+
+// ============ Migration Module Functions ============
+
+pub mod migrations {
+    use super::*;
+
+    /// Parses `data` as `T` and brings it to the current version. Accounts
+    /// already at the current size are upgraded in place; anything else
+    /// must be in the first-release layout of `T`.
+    pub fn upgrade<T>(data: &[u8]) -> Result<T>
+    where
+        T: AccountDeserialize + Versioned,
+    {
+        if let Ok(mut account) = T::try_deserialize(&mut &data[..]) {
+            if account.space() == data.len() {
+                require!(account.version() < ACCOUNT_VERSION, TidesError::NotLegacyAccount);
+                account.upgrade();
+                return Ok(account);
+            }
+        }
+        T::from_v0(data)
+    }
+
+    /// Stamps the current version on a leaderboard. Leaderboards were
+    /// zero-copy before they were versioned, with padding where the version
+    /// byte now lives.
+    pub fn upgrade_leaderboard(data: &mut [u8]) -> Result<()> {
+        require!(data.len() == SeasonLeaderboard::LEN, TidesError::NotLegacyAccount);
+        let version = &mut data[LEADERBOARD_VERSION_OFFSET];
+        require!(*version < ACCOUNT_VERSION, TidesError::NotLegacyAccount);
+        *version = ACCOUNT_VERSION;
+        Ok(())
+    }
+
+    /// Upgrades any Borsh account of this program to the current version,
+    /// dispatching on its discriminator. `payer` covers the extra rent.
+    pub fn migrate_account<'info>(
+        account: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        require!(account.owner == &crate::ID, TidesError::InvalidAddress);
+
+        let discriminator: [u8; 8] = account
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+
+        match discriminator {
+            GameState::DISCRIMINATOR => migrate::<GameState>(account, payer, system_program),
+            PlayerState::DISCRIMINATOR => migrate::<PlayerState>(account, payer, system_program),
+            FishMarketData::DISCRIMINATOR => migrate::<FishMarketData>(account, payer, system_program),
+            ShardData::DISCRIMINATOR => migrate::<ShardData>(account, payer, system_program),
+            ShardDirectory::DISCRIMINATOR => migrate::<ShardDirectory>(account, payer, system_program),
+            AccessControl::DISCRIMINATOR => migrate::<AccessControl>(account, payer, system_program),
+            FishingState::DISCRIMINATOR => migrate::<FishingState>(account, payer, system_program),
+            PlayerBait::DISCRIMINATOR => migrate::<PlayerBait>(account, payer, system_program),
+            OracleSet::DISCRIMINATOR => migrate::<OracleSet>(account, payer, system_program),
+            PendingChange::DISCRIMINATOR => migrate::<PendingChange>(account, payer, system_program),
+            PlayerInventory::DISCRIMINATOR => migrate::<PlayerInventory>(account, payer, system_program),
+            FishCatch::DISCRIMINATOR => migrate::<FishCatch>(account, payer, system_program),
+            FishRegistry::DISCRIMINATOR => migrate::<FishRegistry>(account, payer, system_program),
+            FishSpecies::DISCRIMINATOR => migrate::<FishSpecies>(account, payer, system_program),
+            BaitType::DISCRIMINATOR => migrate::<BaitType>(account, payer, system_program),
+            ShipRegistry::DISCRIMINATOR => migrate::<ShipRegistry>(account, payer, system_program),
+            Ship::DISCRIMINATOR => migrate::<Ship>(account, payer, system_program),
+            EngineRegistry::DISCRIMINATOR => migrate::<EngineRegistry>(account, payer, system_program),
+            Engine::DISCRIMINATOR => migrate::<Engine>(account, payer, system_program),
+            FishingRodRegistry::DISCRIMINATOR => {
+                migrate::<FishingRodRegistry>(account, payer, system_program)
+            }
+            FishingRod::DISCRIMINATOR => migrate::<FishingRod>(account, payer, system_program),
+            MapRegistry::DISCRIMINATOR => migrate::<MapRegistry>(account, payer, system_program),
+            Map::DISCRIMINATOR => migrate::<Map>(account, payer, system_program),
+            SeasonPassState::DISCRIMINATOR => {
+                migrate::<SeasonPassState>(account, payer, system_program)
+            }
+            Season::DISCRIMINATOR => migrate::<Season>(account, payer, system_program),
+            SeasonVault::DISCRIMINATOR => migrate::<SeasonVault>(account, payer, system_program),
+            PlayerSeasonStats::DISCRIMINATOR => {
+                migrate::<PlayerSeasonStats>(account, payer, system_program)
+            }
+            SeasonTrack::DISCRIMINATOR => migrate::<SeasonTrack>(account, payer, system_program),
+            SessionToken::DISCRIMINATOR => migrate::<SessionToken>(account, payer, system_program),
+            _ => err!(TidesError::InvalidAddress),
+        }
+    }
+
+    /// Reads the game state of a first-release game, a keypair account, as
+    /// the current layout for the game state PDA to take over.
+    pub fn legacy_game_state(account: &AccountInfo) -> Result<GameState> {
+        require!(account.owner == &crate::ID, TidesError::InvalidAddress);
+        GameState::from_v0(&account.try_borrow_data()?)
+    }
+
+    /// Whether `account` is a shard still in the first-release layout.
+    pub fn is_v0_shard(account: &AccountInfo) -> Result<bool> {
+        Ok(account.owner == &crate::ID
+            && account.data_len() == v0::ShardData::LEN
+            && account.try_borrow_data()?[..8] == ShardData::DISCRIMINATOR)
+    }
+
+    /// Stamps the current version on a leaderboard in place.
+    pub fn migrate_leaderboard(account: &AccountInfo) -> Result<()> {
+        require!(account.owner == &crate::ID, TidesError::InvalidAddress);
+
+        let mut data = account.try_borrow_mut_data()?;
+        require!(
+            data.get(..8) == Some(&SeasonLeaderboard::DISCRIMINATOR[..]),
+            TidesError::InvalidAddress
+        );
+        upgrade_leaderboard(&mut data)
+    }
+
+    fn migrate<'info, T>(
+        account: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()>
+    where
+        T: AccountSerialize + AccountDeserialize + Versioned,
+    {
+        let upgraded = upgrade::<T>(&account.try_borrow_data()?)?;
+        let space = upgraded.space();
+
+        let rent_due = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(account.lamports());
+        if rent_due > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+            system_program::transfer(cpi_ctx, rent_due)?;
+        }
+
+        account.realloc(space, false)?;
+        upgraded.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{ChangeStatus, ParameterChange, RoleMember};
+
+        // Layouts of earlier versioned releases
+
+        #[derive(AnchorSerialize)]
+        struct PlayerStateV1 {
+            player: Pubkey,
+            map_id: u64,
+            ship_id: u64,
+            current_fuel: u64,
+            last_move_timestamp: i64,
+            next_move_time: i64,
+            movement_speed: u64,
+            position_x: i32,
+            position_y: i32,
+            shard: u8,
+            is_active: bool,
+            last_shard_change: i64,
+            version: u8,
+        }
+
+        #[derive(AnchorSerialize)]
+        struct PendingChangeV3 {
+            change_id: u64,
+            change: ParameterChange,
+            proposer: Pubkey,
+            proposed_at: i64,
+            executable_at: i64,
+            status: ChangeStatus,
+            version: u8,
+        }
+
+        #[derive(AnchorSerialize)]
+        struct AccessControlV3 {
+            members: Vec<RoleMember>,
+            version: u8,
+        }
+
+        /// Discriminator and Borsh data of `account`, zero-filled to `space`
+        /// bytes like an account allocated by `init`.
+        fn fixture<V: AnchorSerialize>(discriminator: [u8; 8], account: &V, space: usize) -> Vec<u8> {
+            let mut data = discriminator.to_vec();
+            account.serialize(&mut data).unwrap();
+            assert!(data.len() <= space);
+            data.resize(space, 0);
+            data
+        }
+
+        fn key(seed: u8) -> Pubkey {
+            Pubkey::new_from_array([seed; 32])
+        }
+
+        fn assert_not_legacy(result: Result<impl Sized>) {
+            assert_eq!(result.err(), Some(TidesError::NotLegacyAccount.into()));
+        }
+
+        fn game_state_v0(paused: bool) -> v0::GameState {
+            v0::GameState {
+                currency_mint: key(1),
+                admin: key(2),
+                paused,
+                max_players_per_shard: 500,
+                server_signer: key(3),
+            }
+        }
+
+        fn player_state_v0() -> v0::PlayerState {
+            v0::PlayerState {
+                player: key(4),
+                map_id: 2,
+                ship_id: 3,
+                current_fuel: 42,
+                last_move_timestamp: 1_700_000_000,
+                next_move_time: 1_700_000_060,
+                movement_speed: 5,
+                position_x: -12,
+                position_y: 9,
+                shard: 17,
+                is_active: true,
+            }
+        }
+
+        #[test]
+        fn upgrades_game_state() {
+            let v0 = game_state_v0(false);
+            let data = fixture(GameState::DISCRIMINATOR, &v0, v0::GameState::LEN);
+
+            assert!(GameState::try_deserialize(&mut &data[..]).is_err());
+
+            let game_state = upgrade::<GameState>(&data).unwrap();
+            assert_eq!(game_state.currency_mint, v0.currency_mint);
+            assert_eq!(game_state.admin, v0.admin);
+            assert_eq!(game_state.paused, 0);
+            assert_eq!(game_state.max_players_per_shard, v0.max_players_per_shard);
+            assert_eq!(game_state.server_signer, v0.server_signer);
+            assert_eq!(game_state.shard_change_fee, 0);
+            assert_eq!(game_state.pending_admin, Pubkey::default());
+            assert_eq!(game_state.timelock_delay, DEFAULT_TIMELOCK_DELAY);
+            assert_eq!(game_state.next_change_id, 0);
+            assert_eq!(game_state.version, ACCOUNT_VERSION);
+            assert_eq!(game_state.space(), GameState::LEN);
+        }
+
+        #[test]
+        fn keeps_paused_game_paused() {
+            let data = fixture(GameState::DISCRIMINATOR, &game_state_v0(true), v0::GameState::LEN);
+
+            let game_state = upgrade::<GameState>(&data).unwrap();
+            assert_eq!(game_state.paused, PAUSE_ALL);
+        }
+
+        #[test]
+        fn upgrades_player_state() {
+            let v0 = player_state_v0();
+            let data = fixture(PlayerState::DISCRIMINATOR, &v0, v0::PlayerState::LEN);
+
+            let player_state = upgrade::<PlayerState>(&data).unwrap();
+            assert_eq!(player_state.player, v0.player);
+            assert_eq!(player_state.map_id, v0.map_id);
+            assert_eq!(player_state.ship_id, v0.ship_id);
+            assert_eq!(player_state.current_fuel, v0.current_fuel);
+            assert_eq!(player_state.last_move_timestamp, v0.last_move_timestamp);
+            assert_eq!(player_state.next_move_time, v0.next_move_time);
+            assert_eq!(player_state.movement_speed, v0.movement_speed);
+            assert_eq!(player_state.position_x, v0.position_x);
+            assert_eq!(player_state.position_y, v0.position_y);
+            assert_eq!(player_state.shard, v0.shard);
+            assert_eq!(player_state.is_active, v0.is_active);
+            assert_eq!(player_state.last_shard_change, 0);
+            assert_eq!(player_state.version, ACCOUNT_VERSION);
+            assert_eq!(player_state.game_state, GameState::address());
+        }
+
+        #[test]
+        fn upgrades_shard_data() {
+            let v0 = v0::ShardData {
+                shard_id: 17,
+                player_count: 312,
+            };
+            let data = fixture(ShardData::DISCRIMINATOR, &v0, v0::ShardData::LEN);
+
+            let shard_data = upgrade::<ShardData>(&data).unwrap();
+            assert_eq!(shard_data.shard_id, v0.shard_id);
+            assert_eq!(shard_data.player_count, v0.player_count);
+            assert_eq!(shard_data.version, ACCOUNT_VERSION);
+            assert_eq!(shard_data.space(), ShardData::LEN);
+        }
+
+        #[test]
+        fn moves_keypair_game_state_to_pda() {
+            let v0 = game_state_v0(true);
+            let mut data = fixture(GameState::DISCRIMINATOR, &v0, v0::GameState::LEN);
+            // The first release created the game state with a fresh keypair
+            let legacy_key = key(20);
+            assert_ne!(legacy_key, GameState::address());
+            let mut lamports = 1_000_000;
+            let legacy = AccountInfo::new(&legacy_key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+
+            let game_state = legacy_game_state(&legacy).unwrap();
+            assert_eq!(game_state.currency_mint, v0.currency_mint);
+            assert_eq!(game_state.admin, v0.admin);
+            assert_eq!(game_state.paused, PAUSE_ALL);
+            assert_eq!(game_state.max_players_per_shard, v0.max_players_per_shard);
+            assert_eq!(game_state.server_signer, v0.server_signer);
+            assert_eq!(game_state.version, ACCOUNT_VERSION);
+
+            // Its players and shards move over to the PDA as they are upgraded
+            let player_data = fixture(PlayerState::DISCRIMINATOR, &player_state_v0(), v0::PlayerState::LEN);
+            let player_state = upgrade::<PlayerState>(&player_data).unwrap();
+            assert_eq!(player_state.game_state, GameState::address());
+
+            let shard_key = ShardData::address(17);
+            let mut shard_lamports = 1_000_000;
+            let v0_shard = v0::ShardData {
+                shard_id: 17,
+                player_count: 312,
+            };
+            let mut shard_data = fixture(ShardData::DISCRIMINATOR, &v0_shard, v0::ShardData::LEN);
+            let shard = AccountInfo::new(&shard_key, false, true, &mut shard_lamports, &mut shard_data, &crate::ID, false, 0);
+            assert!(is_v0_shard(&shard).unwrap());
+        }
+
+        #[test]
+        fn rejects_game_state_of_other_programs() {
+            let mut data = fixture(GameState::DISCRIMINATOR, &game_state_v0(false), v0::GameState::LEN);
+            let legacy_key = key(20);
+            let owner = key(21);
+            let mut lamports = 1_000_000;
+            let legacy = AccountInfo::new(&legacy_key, false, true, &mut lamports, &mut data, &owner, false, 0);
+
+            assert_eq!(
+                legacy_game_state(&legacy).err(),
+                Some(TidesError::InvalidAddress.into())
+            );
+        }
+
+        #[test]
+        fn links_v1_player_state_to_game() {
+            let v1 = PlayerStateV1 {
+                player: key(4),
+                map_id: 2,
+                ship_id: 3,
+                current_fuel: 42,
+                last_move_timestamp: 1_700_000_000,
+                next_move_time: 1_700_000_060,
+                movement_speed: 5,
+                position_x: -12,
+                position_y: 9,
+                shard: 17,
+                is_active: true,
+                last_shard_change: 1_699_000_000,
+                version: 1,
+            };
+            // v1 had reserved space where game_state now lives
+            let data = fixture(PlayerState::DISCRIMINATOR, &v1, PlayerState::LEN);
+
+            let player_state = upgrade::<PlayerState>(&data).unwrap();
+            assert_eq!(player_state.player, v1.player);
+            assert_eq!(player_state.position_x, v1.position_x);
+            assert_eq!(player_state.last_shard_change, v1.last_shard_change);
+            assert_eq!(player_state.version, ACCOUNT_VERSION);
+            assert_eq!(player_state.game_state, GameState::address());
+        }
+
+        #[test]
+        fn gives_v3_pending_changes_an_expiry() {
+            let v3 = PendingChangeV3 {
+                change_id: 4,
+                change: ParameterChange::ShardChangeFee(25),
+                proposer: key(10),
+                proposed_at: 1_700_000_000,
+                executable_at: 1_700_086_400,
+                status: ChangeStatus::Pending,
+                version: 3,
+            };
+            let data = fixture(PendingChange::DISCRIMINATOR, &v3, PendingChange::LEN);
+
+            let pending_change = upgrade::<PendingChange>(&data).unwrap();
+            assert_eq!(pending_change.change, v3.change);
+            assert_eq!(pending_change.executable_at, v3.executable_at);
+            assert_eq!(pending_change.expires_at, v3.executable_at + CHANGE_EXECUTION_WINDOW);
+            assert_eq!(pending_change.version, ACCOUNT_VERSION);
+        }
+
+        #[test]
+        fn clears_reserved_bytes() {
+            let v3 = AccessControlV3 {
+                members: vec![RoleMember { account: key(9), roles: 0b1 }],
+                version: 3,
+            };
+            let mut data = fixture(AccessControl::DISCRIMINATOR, &v3, AccessControl::LEN);
+            data[AccessControl::LEN - ACCOUNT_RESERVED_BYTES..].fill(0xAB);
+
+            let access_control = upgrade::<AccessControl>(&data).unwrap();
+            assert_eq!(access_control.members, v3.members);
+            assert_eq!(access_control.version, ACCOUNT_VERSION);
+            assert_eq!(access_control._reserved, [0; ACCOUNT_RESERVED_BYTES]);
+        }
+
+        #[test]
+        fn rejects_current_accounts() {
+            let mut data = fixture(PlayerState::DISCRIMINATOR, &player_state_v0(), PlayerState::LEN);
+            data[PlayerState::LEN - VERSION_FOOTPRINT] = ACCOUNT_VERSION;
+
+            assert_not_legacy(upgrade::<PlayerState>(&data));
+            assert_not_legacy(PlayerState::from_v0(&data));
+        }
+
+        #[test]
+        fn rejects_types_without_v0_layout() {
+            // The first release never created access control accounts
+            let mut data = AccessControl::DISCRIMINATOR.to_vec();
+            data.extend_from_slice(&0u32.to_le_bytes());
+
+            assert_not_legacy(upgrade::<AccessControl>(&data));
+        }
+
+        #[test]
+        fn rejects_other_account_types() {
+            let v0 = v0::ShardData {
+                shard_id: 1,
+                player_count: 0,
+            };
+            let data = fixture(ShardData::DISCRIMINATOR, &v0, v0::ShardData::LEN);
+
+            assert!(upgrade::<PlayerState>(&data).is_err());
+            assert!(upgrade::<OracleSet>(&data).is_err());
+        }
+
+        #[test]
+        fn upgrades_leaderboard_once() {
+            let mut data = vec![0; SeasonLeaderboard::LEN];
+            data[..8].copy_from_slice(&SeasonLeaderboard::DISCRIMINATOR);
+
+            upgrade_leaderboard(&mut data).unwrap();
+            assert_eq!(data[LEADERBOARD_VERSION_OFFSET], ACCOUNT_VERSION);
+
+            assert_not_legacy(upgrade_leaderboard(&mut data));
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use crate::currents::currents;
+use crate::hex::hex;
+use crate::registries::registries;
+use crate::{
+    Hex, HexDirection, Map, PlayerState, TidesError, MAX_COORDINATE,
+    MAX_MOVE_DIRECTIONS, MIN_COORDINATE, MIN_STEP_COST,
+};
+
+// ============ Pathfinding Types ============
+
+/// Which hexes of a map a ship can sail through. Terrain is not stored on
+/// chain, so clients and the server supply their own.
+pub trait Terrain {
+    fn is_passable(&self, hex: Hex) -> bool;
+}
+
+/// Every hex is open water.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpenWater;
+
+impl Terrain for OpenWater {
+    fn is_passable(&self, _hex: Hex) -> bool {
+        true
+    }
+}
+
+/// The set of blocked hexes (land, reefs).
+impl Terrain for HashSet<Hex> {
+    fn is_passable(&self, hex: Hex) -> bool {
+        !self.contains(&hex)
+    }
+}
+
+/// Cheapest route between two hexes, ready to send as `move_player` calls.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Route {
+    pub path: Vec<Hex>,        // Start and goal included
+    pub directions: Vec<u8>,   // HexDirection indices, one per step
+    pub moves: Vec<Vec<u8>>,   // `directions` split into MAX_MOVE_DIRECTIONS chunks
+    pub move_fuel: Vec<u64>,   // Fuel of each move, sent as soon as the cooldown allows
+    pub fuel_cost: u64,        // Total fuel, as charged by `move_player`
+    pub cooldown: i64,         // Total move cooldown in seconds across all moves
+}
+
+// ============ Pathfinding Module Functions ============
+
+pub mod pathfinding {
+    use super::*;
+
+    /// Whether a ship may enter `hex` on `map`.
+    pub fn is_navigable(map: &Map, terrain: &impl Terrain, hex: Hex) -> bool {
+        registries::is_valid_position(map, hex)
+            && hex.is_within(MIN_COORDINATE, MAX_COORDINATE)
+            && terrain.is_passable(hex)
+    }
+
+    /// Plans the cheapest route from the player's position to `goal`, within
+    /// their current fuel, leaving as soon as their cooldown allows.
+    pub fn find_route(
+        map: &Map,
+        terrain: &impl Terrain,
+        player: &PlayerState,
+        goal: Hex,
+        now: i64,
+    ) -> Result<Route> {
+        plan_route(
+            map,
+            terrain,
+            player.position(),
+            goal,
+            player.current_fuel,
+            player.movement_speed,
+            now.max(player.next_move_time),
+        )
+    }
+
+    /// A* search over `map` from `start` to `goal`, leaving at `departure`.
+    /// Steps are priced with the wind at departure; the reported fuel
+    /// re-prices each move at the time it can be sent. Fails with
+    /// `InsufficientFuel` when that costs more than `fuel`.
+    pub fn plan_route(
+        map: &Map,
+        terrain: &impl Terrain,
+        start: Hex,
+        goal: Hex,
+        fuel: u64,
+        movement_speed: u64,
+        departure: i64,
+    ) -> Result<Route> {
+        require!(is_navigable(map, terrain, goal), TidesError::PositionOutOfBounds);
+
+        let path = search(map, terrain, start, goal, departure).ok_or(TidesError::NoRouteFound)?;
+        let directions = hex::encode_path(&path)?;
+        let moves: Vec<Vec<u8>> = directions
+            .chunks(MAX_MOVE_DIRECTIONS)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        // Each move goes out when the previous one's cooldown, `movement_speed`
+        // per hex, ends
+        let mut move_fuel = Vec::with_capacity(moves.len());
+        let mut sent_at = departure;
+        let mut position = start;
+        for directions in &moves {
+            move_fuel.push(currents::route_cost(map, position, directions, sent_at)?);
+            position = hex::walk(position, directions)?;
+            sent_at = movement_speed
+                .checked_mul(directions.len() as u64)
+                .and_then(|cooldown| i64::try_from(cooldown).ok())
+                .and_then(|cooldown| sent_at.checked_add(cooldown))
+                .ok_or(TidesError::MathOverflow)?;
+        }
+        let fuel_cost = move_fuel
+            .iter()
+            .try_fold(0u64, |total, fuel| total.checked_add(*fuel))
+            .ok_or(TidesError::MathOverflow)?;
+        require!(fuel_cost <= fuel, TidesError::InsufficientFuel);
+
+        Ok(Route {
+            path,
+            directions,
+            moves,
+            move_fuel,
+            fuel_cost,
+            cooldown: sent_at - departure,
+        })
+    }
+
+    fn search(
+        map: &Map,
+        terrain: &impl Terrain,
+        start: Hex,
+        goal: Hex,
+        departure: i64,
+    ) -> Option<Vec<Hex>> {
+        // Admissible since no step costs less than MIN_STEP_COST
+        let heuristic = |hex: Hex| hex.distance(goal).saturating_mul(MIN_STEP_COST);
+
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<Hex, Hex> = HashMap::new();
+        let mut best: HashMap<Hex, u64> = HashMap::new();
+        // Insertion order breaks ties so routes are deterministic
+        let mut pushed: u64 = 0;
+
+        best.insert(start, 0);
+        open.push(Reverse((heuristic(start), pushed, start.q, start.r)));
+
+        while let Some(Reverse((_, _, q, r))) = open.pop() {
+            let current = Hex::new(q, r);
+            if current == goal {
+                let mut path = vec![goal];
+                while let Some(previous) = came_from.get(path.last()?) {
+                    path.push(*previous);
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let cost = best[&current];
+            for heading in HexDirection::ALL {
+                let Some(next) = current.neighbor(heading) else {
+                    continue;
+                };
+                if !is_navigable(map, terrain, next) {
+                    continue;
+                }
+                let step = currents::step_cost(map, current, heading, departure);
+                let next_cost = cost.saturating_add(step);
+                if best.get(&next).map_or(true, |known| next_cost < *known) {
+                    best.insert(next, next_cost);
+                    came_from.insert(next, current);
+                    pushed += 1;
+                    let estimate = next_cost.saturating_add(heuristic(next));
+                    open.push(Reverse((estimate, pushed, next.q, next.r)));
+                }
+            }
+        }
+
+        None
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{Current, ACCOUNT_RESERVED_BYTES, CURRENT_REGIONS, HEX_MOVE_COST};
+
+        const DEPARTURE: i64 = 1_700_000_000;
+
+        fn map(min: i32, max: i32) -> Map {
+            Map {
+                registry: Pubkey::default(),
+                map_id: 1,
+                travel_cost: 0,
+                min_x: min,
+                max_x: max,
+                min_y: min,
+                max_y: max,
+                harbors: vec![(0, 0)],
+                version: 0,
+                currents: [Current::default(); CURRENT_REGIONS],
+                _reserved: [0; ACCOUNT_RESERVED_BYTES - CURRENT_REGIONS * Current::LEN],
+            }
+        }
+
+        fn plan(map: &Map, terrain: &impl Terrain, start: Hex, goal: Hex) -> Result<Route> {
+            plan_route(map, terrain, start, goal, u64::MAX, 10, DEPARTURE)
+        }
+
+        #[test]
+        fn routes_in_open_water_are_shortest() {
+            let map = map(-50, 50);
+            let route = plan(&map, &OpenWater, Hex::new(0, 0), Hex::new(30, -5)).unwrap();
+            assert_eq!(route.directions.len() as u64, Hex::new(0, 0).distance(Hex::new(30, -5)));
+            assert_eq!(route.path.len(), route.directions.len() + 1);
+            assert_eq!(hex::walk(Hex::new(0, 0), &route.directions).unwrap(), Hex::new(30, -5));
+            assert_eq!(route.cooldown, 300);
+            assert_eq!(route.moves.len(), 2);
+            assert_eq!(route.moves[0].len(), MAX_MOVE_DIRECTIONS);
+            assert_eq!(route.moves.concat(), route.directions);
+        }
+
+        #[test]
+        fn prices_each_move_when_it_is_sent() {
+            let map = map(-50, 50);
+            let route = plan(&map, &OpenWater, Hex::new(-20, 0), Hex::new(25, 0)).unwrap();
+            assert_eq!(route.moves.len(), 3);
+
+            let mut sent_at = DEPARTURE;
+            let mut position = Hex::new(-20, 0);
+            for (directions, fuel) in route.moves.iter().zip(&route.move_fuel) {
+                assert_eq!(*fuel, currents::route_cost(&map, position, directions, sent_at).unwrap());
+                position = hex::walk(position, directions).unwrap();
+                sent_at += 10 * directions.len() as i64;
+            }
+            assert_eq!(route.fuel_cost, route.move_fuel.iter().sum::<u64>());
+            assert_eq!(route.cooldown, sent_at - DEPARTURE);
+        }
+
+        #[test]
+        fn rides_currents_when_cheaper() {
+            // Calm except a strong westward current along the top half
+            let mut map = map(-8, 7);
+            for region in 0..CURRENT_REGIONS / 2 {
+                map.currents[region] = Current { direction: 3, strength: 50 };
+            }
+            let start = Hex::new(6, 0);
+            let goal = Hex::new(-6, 0);
+            let route = plan(&map, &OpenWater, start, goal).unwrap();
+            assert!(route.directions.len() as u64 > start.distance(goal));
+            assert!(route.path.iter().any(|hex| hex.r < 0));
+
+            let direct = vec![3u8; start.distance(goal) as usize];
+            assert!(route.fuel_cost < currents::route_cost(&map, start, &direct, DEPARTURE).unwrap());
+        }
+
+        #[test]
+        fn routes_around_blocked_hexes() {
+            // A wall at q = 2 with a single gap at r = 3
+            let walls: HashSet<Hex> = (-10..=10).filter(|r| *r != 3).map(|r| Hex::new(2, r)).collect();
+            let route = plan(&map(-10, 10), &walls, Hex::new(0, 0), Hex::new(4, 0)).unwrap();
+            assert!(route.path.contains(&Hex::new(2, 3)));
+            assert!(route.path.iter().all(|hex| walls.is_passable(*hex)));
+            for pair in route.path.windows(2) {
+                assert_eq!(pair[0].distance(pair[1]), 1);
+            }
+        }
+
+        #[test]
+        fn stays_on_the_map() {
+            let small = map(0, 3);
+            let route = plan(&small, &OpenWater, Hex::new(0, 3), Hex::new(3, 0)).unwrap();
+            assert!(route.path.iter().all(|hex| is_navigable(&small, &OpenWater, *hex)));
+            assert!(plan(&small, &OpenWater, Hex::new(0, 0), Hex::new(4, 0)).is_err());
+        }
+
+        #[test]
+        fn rejects_unreachable_and_unaffordable_goals() {
+            let map = map(-10, 10);
+            let island: HashSet<Hex> = Hex::new(5, 5).neighbors().into_iter().collect();
+            assert!(plan(&map, &island, Hex::new(0, 0), Hex::new(5, 5)).is_err());
+            assert!(plan_route(&map, &OpenWater, Hex::new(0, 0), Hex::new(3, 0), HEX_MOVE_COST, 1, DEPARTURE)
+                .is_err());
+            let route = plan_route(&map, &OpenWater, Hex::new(0, 0), Hex::new(0, 0), 0, 1, DEPARTURE).unwrap();
+            assert!(route.directions.is_empty() && route.moves.is_empty());
+            assert_eq!((route.fuel_cost, route.cooldown), (0, 0));
+        }
+    }
+}
@@ -1,18 +1,26 @@
 use anchor_lang::prelude::*;
-use crate::{TidesError, SlotType};
+use crate::{Current, Hex, TidesError, SlotType, ACCOUNT_RESERVED_BYTES, CURRENT_REGIONS};
 
 // ============ Registry Account Structures ============
 
 #[account]
 pub struct FishRegistry {
-    pub admin: Pubkey,
+    pub admin: Pubkey, // Handed over with propose_admin; registry instructions check RegistryCurator
     pub species_count: u64,
+    pub pending_admin: Pubkey, // Proposed admin, default when none
+    pub version: u8,
+    pub game_state: Pubkey, // GameState this account belongs to
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 32],
 }
 
 impl FishRegistry {
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
-        8; // species_count
+        8 + // species_count
+        32 + // pending_admin
+        1 + // version
+        32 + // game_state
+        ACCOUNT_RESERVED_BYTES - 32; // _reserved
 }
 
 #[account]
@@ -23,6 +31,8 @@ pub struct FishSpecies {
     pub shape_width: u8,
     pub shape_height: u8,
     pub shape_data: Vec<u8>,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl FishSpecies {
@@ -33,7 +43,9 @@ impl FishSpecies {
         8 + // base_price
         1 + // shape_width
         1 + // shape_height
-        4 + shape_data_len // shape_data Vec
+        4 + shape_data_len + // shape_data Vec
+        1 + // version
+        ACCOUNT_RESERVED_BYTES // _reserved
     }
 }
 
@@ -44,6 +56,8 @@ pub struct BaitType {
     pub name: String,
     pub price: u64,
     pub is_active: bool,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl BaitType {
@@ -53,20 +67,30 @@ impl BaitType {
         8 + // bait_id
         4 + name_len + // name String
         8 + // price
-        1 // is_active
+        1 + // is_active
+        1 + // version
+        ACCOUNT_RESERVED_BYTES // _reserved
     }
 }
 
 #[account]
 pub struct ShipRegistry {
-    pub admin: Pubkey,
+    pub admin: Pubkey, // Handed over with propose_admin; registry instructions check RegistryCurator
     pub ship_count: u64,
+    pub pending_admin: Pubkey, // Proposed admin, default when none
+    pub version: u8,
+    pub game_state: Pubkey, // GameState this account belongs to
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 32],
 }
 
 impl ShipRegistry {
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
-        8; // ship_count
+        8 + // ship_count
+        32 + // pending_admin
+        1 + // version
+        32 + // game_state
+        ACCOUNT_RESERVED_BYTES - 32; // _reserved
 }
 
 #[account]
@@ -78,6 +102,8 @@ pub struct Ship {
     pub purchase_price: u64,
     pub durability: u64, // Used as proxy for ship weight
     pub slot_types: Vec<SlotType>,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl Ship {
@@ -89,20 +115,30 @@ impl Ship {
         1 + // cargo_height
         8 + // purchase_price
         8 + // durability
-        4 + (cargo_width as usize * cargo_height as usize) // slot_types Vec
+        4 + (cargo_width as usize * cargo_height as usize) + // slot_types Vec
+        1 + // version
+        ACCOUNT_RESERVED_BYTES // _reserved
     }
 }
 
 #[account]
 pub struct EngineRegistry {
-    pub admin: Pubkey,
+    pub admin: Pubkey, // Handed over with propose_admin; registry instructions check RegistryCurator
     pub engine_count: u64,
+    pub pending_admin: Pubkey, // Proposed admin, default when none
+    pub version: u8,
+    pub game_state: Pubkey, // GameState this account belongs to
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 32],
 }
 
 impl EngineRegistry {
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
-        8; // engine_count
+        8 + // engine_count
+        32 + // pending_admin
+        1 + // version
+        32 + // game_state
+        ACCOUNT_RESERVED_BYTES - 32; // _reserved
 }
 
 #[account]
@@ -115,6 +151,8 @@ pub struct Engine {
     pub shape_width: u8,
     pub shape_height: u8,
     pub shape_data: Vec<u8>,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl Engine {
@@ -127,20 +165,30 @@ impl Engine {
         8 + // purchase_price
         1 + // shape_width
         1 + // shape_height
-        4 + shape_data_len // shape_data Vec
+        4 + shape_data_len + // shape_data Vec
+        1 + // version
+        ACCOUNT_RESERVED_BYTES // _reserved
     }
 }
 
 #[account]
 pub struct FishingRodRegistry {
-    pub admin: Pubkey,
+    pub admin: Pubkey, // Handed over with propose_admin; registry instructions check RegistryCurator
     pub rod_count: u64,
+    pub pending_admin: Pubkey, // Proposed admin, default when none
+    pub version: u8,
+    pub game_state: Pubkey, // GameState this account belongs to
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 32],
 }
 
 impl FishingRodRegistry {
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
-        8; // rod_count
+        8 + // rod_count
+        32 + // pending_admin
+        1 + // version
+        32 + // game_state
+        ACCOUNT_RESERVED_BYTES - 32; // _reserved
 }
 
 #[account]
@@ -151,6 +199,8 @@ pub struct FishingRod {
     pub shape_width: u8,
     pub shape_height: u8,
     pub shape_data: Vec<u8>,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl FishingRod {
@@ -161,20 +211,30 @@ impl FishingRod {
         8 + // purchase_price
         1 + // shape_width
         1 + // shape_height
-        4 + shape_data_len // shape_data Vec
+        4 + shape_data_len + // shape_data Vec
+        1 + // version
+        ACCOUNT_RESERVED_BYTES // _reserved
     }
 }
 
 #[account]
 pub struct MapRegistry {
-    pub admin: Pubkey,
+    pub admin: Pubkey, // Handed over with propose_admin; registry instructions check RegistryCurator
     pub map_count: u64,
+    pub pending_admin: Pubkey, // Proposed admin, default when none
+    pub version: u8,
+    pub game_state: Pubkey, // GameState this account belongs to
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 32],
 }
 
 impl MapRegistry {
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
-        8; // map_count
+        8 + // map_count
+        32 + // pending_admin
+        1 + // version
+        32 + // game_state
+        ACCOUNT_RESERVED_BYTES - 32; // _reserved
 }
 
 #[account]
@@ -187,6 +247,9 @@ pub struct Map {
     pub min_y: i32,
     pub max_y: i32,
     pub harbors: Vec<(i32, i32)>, // Harbor positions
+    pub version: u8,
+    pub currents: [Current; CURRENT_REGIONS], // Row-major over the current grid
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - CURRENT_REGIONS * Current::LEN],
 }
 
 impl Map {
@@ -199,7 +262,10 @@ impl Map {
         4 + // max_x
         4 + // min_y
         4 + // max_y
-        4 + (harbor_count * 8) // harbors Vec (each (i32, i32) = 8 bytes)
+        4 + (harbor_count * 8) + // harbors Vec (each (i32, i32) = 8 bytes)
+        1 + // version
+        CURRENT_REGIONS * Current::LEN + // currents
+        ACCOUNT_RESERVED_BYTES - CURRENT_REGIONS * Current::LEN // _reserved
     }
 }
 
@@ -232,12 +298,20 @@ pub mod registries {
         map.map_id == map_id
     }
 
-    pub fn is_valid_position(map: &Account<Map>, x: i32, y: i32) -> bool {
-        x >= map.min_x && x <= map.max_x && y >= map.min_y && y <= map.max_y
+    pub fn is_valid_position(map: &Map, position: Hex) -> bool {
+        (map.min_x..=map.max_x).contains(&position.q) && (map.min_y..=map.max_y).contains(&position.r)
     }
 
-    pub fn is_harbor(map: &Account<Map>, x: i32, y: i32) -> bool {
-        map.harbors.iter().any(|(hx, hy)| *hx == x && *hy == y)
+    pub fn is_harbor(map: &Map, position: Hex) -> bool {
+        map.harbors.iter().any(|harbor| Hex::from(*harbor) == position)
+    }
+
+    /// Nearest harbor to `position`, if the map has any.
+    pub fn nearest_harbor(map: &Map, position: Hex) -> Option<Hex> {
+        map.harbors
+            .iter()
+            .map(|harbor| Hex::from(*harbor))
+            .min_by_key(|harbor| position.distance(*harbor))
     }
 }
 
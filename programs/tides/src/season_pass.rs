@@ -1,25 +1,37 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
-use crate::TidesError;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, MintTo, SetAuthority, Token, TokenAccount};
+use crate::{LeaderboardChanged, SeasonXpEarned, TidesError, ACCOUNT_RESERVED_BYTES, ACCOUNT_VERSION};
 
 // ============ Season Pass Constants ============
 pub const MAX_LEADERBOARD_SIZE: usize = 1000;
 pub const TOP_REWARDS_COUNT: usize = 100;
+pub const BASIS_POINTS: u64 = 10_000;
+pub const MAX_TRACK_TIERS: usize = 64; // Claimed tiers are tracked in a u64 bitmask
 
 // ============ Season Pass Account Structures ============
 
 #[account]
 pub struct SeasonPassState {
-    pub admin: Pubkey,
+    pub admin: Pubkey, // Handed over with propose_admin; season instructions check SeasonManager
     pub current_season_id: u64,
     pub paused: bool,
+    pub treasury: Pubkey, // Receives unclaimed season rewards
+    pub pending_admin: Pubkey, // Proposed admin, default when none
+    pub version: u8,
+    pub last_ended_season_id: u64, // Claims for it may still be open, 0 when none
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES - 8],
 }
 
 impl SeasonPassState {
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
         8 + // current_season_id
-        1; // paused
+        1 + // paused
+        32 + // treasury
+        32 + // pending_admin
+        1 + // version
+        8 + // last_ended_season_id
+        ACCOUNT_RESERVED_BYTES - 8; // _reserved
 }
 
 #[account]
@@ -31,6 +43,22 @@ pub struct Season {
     pub pass_price: u64, // Price in SOL (lamports)
     pub is_active: bool,
     pub total_passes: u64,
+    pub has_ended: bool,
+    pub token_pass_price: u64, // Price in game currency, 0 = SOL only
+    pub prize_pool: u64, // Lamports held in the season vault for rewards
+    pub collection_mint: Pubkey, // Every pass mint is a PDA derived from it
+    pub leaderboard: Pubkey, // SeasonLeaderboard account, default until initialized
+    pub is_finalized: bool,
+    pub reward_pool: u64, // Prize pool snapshot taken at finalization
+    pub claimed_rewards: u64,
+    pub claim_deadline: i64,
+    pub payout_ratio_bps: u16, // Each rank gets this share of the rank above
+    pub reward_count: u32, // Number of ranks paid out
+    pub prize_pool_bps: u16, // Share of each SOL pass sale added to the prize pool
+    pub total_revenue: u64, // Lamports paid for passes
+    pub withdrawn_revenue: u64, // Lamports withdrawn by the admin
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl Season {
@@ -42,10 +70,58 @@ impl Season {
         8 + // end_time
         8 + // pass_price
         1 + // is_active
-        8 // total_passes
+        8 + // total_passes
+        1 + // has_ended
+        8 + // token_pass_price
+        8 + // prize_pool
+        32 + // collection_mint
+        32 + // leaderboard
+        1 + // is_finalized
+        8 + // reward_pool
+        8 + // claimed_rewards
+        8 + // claim_deadline
+        2 + // payout_ratio_bps
+        4 + // reward_count
+        2 + // prize_pool_bps
+        8 + // total_revenue
+        8 + // withdrawn_revenue
+        1 + // version
+        ACCOUNT_RESERVED_BYTES // _reserved
+    }
+
+    /// Lamports the vault still owes to pass holders.
+    pub fn outstanding_prizes(&self) -> u64 {
+        if self.is_finalized {
+            self.reward_pool.saturating_sub(self.claimed_rewards)
+        } else {
+            self.prize_pool
+        }
+    }
+
+    /// Revenue outside the prize pool that has not been withdrawn yet.
+    pub fn withdrawable_revenue(&self) -> u64 {
+        self.total_revenue
+            .saturating_sub(self.prize_pool)
+            .saturating_sub(self.withdrawn_revenue)
     }
 }
 
+/// Holds the lamports paid for a season's passes. Program-owned so that
+/// reward payouts can debit it directly.
+#[account]
+pub struct SeasonVault {
+    pub season_id: u64,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
+}
+
+impl SeasonVault {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // season_id
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+}
+
 #[account]
 pub struct PlayerSeasonStats {
     pub player: Pubkey,
@@ -55,7 +131,13 @@ pub struct PlayerSeasonStats {
     pub net_value: i64, // earnings - spent (can be negative)
     pub last_update_time: i64,
     pub has_pass: bool,
-    pub token_id: Option<u64>, // Season pass NFT token ID
+    pub token_id: Option<Pubkey>, // Season pass NFT mint
+    pub reward_claimed: bool,
+    pub xp: u64,
+    pub free_tiers_claimed: u64, // Bit n set = free reward of tier n claimed
+    pub premium_tiers_claimed: u64, // Bit n set = premium reward of tier n claimed
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
 impl PlayerSeasonStats {
@@ -67,23 +149,192 @@ impl PlayerSeasonStats {
         8 + // net_value (i64)
         8 + // last_update_time
         1 + // has_pass
-        1 + 8; // token_id Option<u64>
+        1 + 32 + // token_id Option<Pubkey>
+        1 + // reward_claimed
+        8 + // xp
+        8 + // free_tiers_claimed
+        8 + // premium_tiers_claimed
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TierRewardKind {
+    #[default]
+    None,      // 0 - Nothing in this lane
+    Bait,      // 1 - `amount` of bait `item_id`
+    Fuel,      // 2 - `amount` of raw fuel, as stored in `current_fuel`
+    Cosmetic,  // 3 - Cosmetic `item_id`, unlocked off-chain from the claim event
+    Currency,  // 4 - `amount` of game currency from the track's reward account
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TierReward {
+    pub kind: TierRewardKind,
+    pub item_id: u64,
+    pub amount: u64,
+}
+
+impl TierReward {
+    pub const LEN: usize = 1 + // kind
+        8 + // item_id
+        8; // amount
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TrackTier {
+    pub xp_required: u64,
+    pub free_reward: TierReward, // Claimable by anyone who joined the season
+    pub premium_reward: TierReward, // Claimable by pass holders only
 }
 
+impl TrackTier {
+    pub const LEN: usize = 8 + // xp_required
+        TierReward::LEN + // free_reward
+        TierReward::LEN; // premium_reward
+}
+
+/// Gameplay that earns season XP.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum XpSource {
+    Catch,
+    Sale,
+    Sailing,
+}
+
+/// Battle-pass track of a season: how much XP gameplay is worth and what
+/// each tier unlocks in the free and premium lanes.
 #[account]
+pub struct SeasonTrack {
+    pub season_id: u64,
+    pub xp_per_catch: u64,
+    pub xp_per_sale: u64,
+    pub xp_per_hex: u64, // Per hex sailed with move_player
+    pub tiers: Vec<TrackTier>, // Sorted by xp_required
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
+}
+
+impl SeasonTrack {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // season_id
+        8 + // xp_per_catch
+        8 + // xp_per_sale
+        8 + // xp_per_hex
+        4 + MAX_TRACK_TIERS * TrackTier::LEN + // tiers Vec
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+
+    /// Address of the track PDA of season `season_id`.
+    pub fn address(season_id: u64) -> Pubkey {
+        Pubkey::find_program_address(&[b"season_track", &season_id.to_le_bytes()], &crate::ID).0
+    }
+
+    /// XP for `units` of `source`, `None` on overflow.
+    pub fn xp_for(&self, source: XpSource, units: u64) -> Option<u64> {
+        let rate = match source {
+            XpSource::Catch => self.xp_per_catch,
+            XpSource::Sale => self.xp_per_sale,
+            XpSource::Sailing => self.xp_per_hex,
+        };
+        rate.checked_mul(units)
+    }
+
+    /// Reward of `tier` in the requested lane, `TierRewardKind::None` if the
+    /// tier does not exist.
+    pub fn reward(&self, tier: u8, premium: bool) -> TierReward {
+        self.tiers
+            .get(tier as usize)
+            .map(|t| if premium { t.premium_reward } else { t.free_reward })
+            .unwrap_or_default()
+    }
+}
+
+/// Top `MAX_LEADERBOARD_SIZE` players of a season, sorted by `net_value`
+/// (highest first). Zero-copy because it is too large to deserialize on
+/// every stat update, so it is created by the client and then initialized.
+/// Both structs are `repr(C)` with no padding, which is what makes them
+/// plain old data.
+#[account(zero_copy(unsafe))]
+#[repr(C)]
 pub struct SeasonLeaderboard {
     pub season_id: u64,
-    pub players: Vec<Pubkey>,
+    pub count: u32,
+    pub version: u8,
+    pub _reserved: [u8; 3],
+    pub entries: [LeaderboardEntry; MAX_LEADERBOARD_SIZE],
+}
+
+#[zero_copy(unsafe)]
+#[repr(C)]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,
+    pub net_value: i64,
 }
 
 impl SeasonLeaderboard {
-    pub fn calculate_size(player_count: usize) -> usize {
-        8 + // discriminator
+    pub const LEN: usize = 8 + // discriminator
         8 + // season_id
-        4 + (player_count * 32) // players Vec
+        4 + // count
+        1 + // version
+        3 + // _reserved
+        MAX_LEADERBOARD_SIZE * (32 + 8); // entries
+
+    /// Zero-based rank of `player`, if they are on the board.
+    pub fn position(&self, player: &Pubkey) -> Option<usize> {
+        self.entries[..self.count as usize]
+            .iter()
+            .position(|entry| entry.player == *player)
+    }
+
+    /// Moves `player` to the rank matching `net_value`, entering them if
+    /// they beat the lowest entry of a full board. Returns the old and new
+    /// zero-based ranks.
+    pub fn upsert(&mut self, player: Pubkey, net_value: i64) -> (Option<usize>, Option<usize>) {
+        let old_rank = self.position(&player);
+        let mut count = self.count as usize;
+
+        match old_rank {
+            Some(rank) => {
+                self.entries.copy_within(rank + 1..count, rank);
+                count -= 1;
+            }
+            None if count == MAX_LEADERBOARD_SIZE => {
+                if net_value <= self.entries[count - 1].net_value {
+                    return (None, None);
+                }
+                count -= 1;
+            }
+            None => {}
+        }
+
+        // Ties keep the player who got there first ahead
+        let new_rank = self.entries[..count]
+            .iter()
+            .position(|entry| entry.net_value < net_value)
+            .unwrap_or(count);
+        self.entries.copy_within(new_rank..count, new_rank + 1);
+        self.entries[new_rank] = LeaderboardEntry { player, net_value };
+        self.count = (count + 1) as u32;
+
+        (old_rank, Some(new_rank))
+    }
+
+    /// Drops `player` from the board, moving everyone below up one rank.
+    /// Returns their old zero-based rank.
+    pub fn remove(&mut self, player: &Pubkey) -> Option<usize> {
+        let rank = self.position(player)?;
+        let count = self.count as usize;
+        self.entries.copy_within(rank + 1..count, rank);
+        self.entries[count - 1] = LeaderboardEntry { player: Pubkey::default(), net_value: 0 };
+        self.count = (count - 1) as u32;
+        Some(rank)
     }
 }
 
+// The fields add up to the struct size, so there are no padding bytes
+const _: () = assert!(std::mem::size_of::<SeasonLeaderboard>() == SeasonLeaderboard::LEN - 8);
+
 // ============ Season Pass Module ============
 
 pub mod season_pass {
@@ -107,8 +358,23 @@ pub mod season_pass {
         season.start_time = start_time;
         season.end_time = end_time;
         season.pass_price = pass_price;
-        season.is_active = true;
+        season.is_active = false;
         season.total_passes = 0;
+        season.has_ended = false;
+        season.token_pass_price = 0;
+        season.prize_pool = 0;
+        season.collection_mint = Pubkey::default();
+        season.leaderboard = Pubkey::default();
+        season.is_finalized = false;
+        season.reward_pool = 0;
+        season.claimed_rewards = 0;
+        season.claim_deadline = 0;
+        season.payout_ratio_bps = 0;
+        season.reward_count = 0;
+        season.prize_pool_bps = BASIS_POINTS as u16;
+        season.total_revenue = 0;
+        season.withdrawn_revenue = 0;
+        season.version = ACCOUNT_VERSION;
 
         Ok(())
     }
@@ -126,8 +392,102 @@ pub mod season_pass {
         Ok(true)
     }
 
-    pub fn update_player_stats(
+    /// Passes go on sale once the season is activated, which may be before
+    /// it starts, so they can change hands ahead of play.
+    pub fn require_pass_sale_open(season: &Account<Season>, clock: &Clock) -> Result<()> {
+        require!(season.is_active, TidesError::SeasonNotActive);
+        require!(clock.unix_timestamp <= season.end_time, TidesError::SeasonHasEnded);
+        Ok(())
+    }
+
+    /// Track rewards unlock once the season starts and stay claimable until
+    /// the claim deadline set at finalization.
+    pub fn require_track_claim_open(season: &Account<Season>, clock: &Clock) -> Result<()> {
+        require!(clock.unix_timestamp >= season.start_time, TidesError::SeasonNotStarted);
+        require!(
+            !season.is_finalized || clock.unix_timestamp <= season.claim_deadline,
+            TidesError::ClaimPeriodOver
+        );
+        Ok(())
+    }
+
+    /// Mints the single pass token and drops the mint authority so supply
+    /// stays fixed at one. The season PDA is the mint authority.
+    pub fn mint_pass<'info>(
+        season: &Account<'info, Season>,
+        season_bump: u8,
+        pass_mint: &Account<'info, Mint>,
+        pass_token_account: &Account<'info, TokenAccount>,
+        token_program: &Program<'info, Token>,
+    ) -> Result<()> {
+        let season_id_bytes = season.season_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[b"season", &season_id_bytes, &[season_bump]]];
+
+        let cpi_accounts = MintTo {
+            mint: pass_mint.to_account_info(),
+            to: pass_token_account.to_account_info(),
+            authority: season.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::mint_to(cpi_ctx, 1)?;
+
+        let cpi_accounts = SetAuthority {
+            current_authority: season.to_account_info(),
+            account_or_mint: pass_mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+
+        Ok(())
+    }
+
+    /// Whether `holder` currently holds the pass token recorded in `stats`.
+    pub fn holds_pass(
+        stats: &PlayerSeasonStats,
+        pass_token_account: &TokenAccount,
+        holder: &Pubkey,
+    ) -> bool {
+        stats.token_id == Some(pass_token_account.mint)
+            && pass_token_account.owner == *holder
+            && pass_token_account.amount == 1
+    }
+
+    pub fn grant_pass(
         stats: &mut Account<PlayerSeasonStats>,
+        season: &mut Account<Season>,
+        player: Pubkey,
+        pass_mint: Pubkey,
+        clock: &Clock,
+    ) -> Result<()> {
+        require!(!stats.has_pass, TidesError::AlreadyOwnsSeasonPass);
+
+        stats.player = player;
+        stats.season_id = season.season_id;
+        stats.last_update_time = clock.unix_timestamp;
+        stats.has_pass = true;
+        stats.token_id = Some(pass_mint);
+        stats.reward_claimed = false;
+        stats.version = ACCOUNT_VERSION;
+        // Earnings, XP and free-lane claims recorded before buying the pass are kept
+
+        season.total_passes = season
+            .total_passes
+            .checked_add(1)
+            .ok_or(TidesError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn update_player_stats(
+        stats: &mut PlayerSeasonStats,
         earnings: u64,
         spent: u64,
         clock: &Clock,
@@ -143,16 +503,300 @@ pub mod season_pass {
             .checked_add(spent)
             .ok_or(TidesError::MathOverflow)?;
 
-        let net_value = stats.total_earnings as i64 - stats.total_spent as i64;
-        stats.net_value = net_value;
+        // Saturate rather than fail so that gameplay never stops on a
+        // net value beyond the i64 range
+        let net_value = stats.total_earnings as i128 - stats.total_spent as i128;
+        stats.net_value = net_value.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
         stats.last_update_time = clock.unix_timestamp;
 
         Ok(())
     }
 
+    /// Loads the player's stats for the current season from their
+    /// `season_stats` PDA, or `None` when they have not joined it.
+    pub fn load_current_stats(info: &AccountInfo) -> Result<Option<PlayerSeasonStats>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        let stats = PlayerSeasonStats::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok(Some(stats))
+    }
+
+    /// Writes back stats loaded with `load_current_stats`.
+    pub fn store_current_stats(info: &AccountInfo, stats: Option<PlayerSeasonStats>) -> Result<()> {
+        if let Some(stats) = stats {
+            stats.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        }
+        Ok(())
+    }
+
+    /// Applies a gameplay spend or payout to the player's stats when they
+    /// have joined the season, and keeps the season leaderboard in step
+    /// once it has been initialized.
+    pub fn record_activity(
+        season: Option<&Account<Season>>,
+        stats: Option<&mut PlayerSeasonStats>,
+        leaderboard: Option<&AccountLoader<SeasonLeaderboard>>,
+        earnings: u64,
+        spent: u64,
+    ) -> Result<()> {
+        let stats = match stats {
+            Some(stats) => stats,
+            None => return Ok(()),
+        };
+        let season = season.ok_or(TidesError::SeasonAccountRequired)?;
+        require!(stats.season_id == season.season_id, TidesError::InvalidId);
+
+        // Activity outside the season's window isn't recorded
+        let clock = Clock::get()?;
+        if !is_season_active(season, &clock)? {
+            return Ok(());
+        }
+
+        // Earnings only count towards the leaderboard for pass holders
+        if !stats.has_pass {
+            return Ok(());
+        }
+
+        update_player_stats(stats, earnings, spent, &clock)?;
+
+        if season.leaderboard != Pubkey::default() {
+            let leaderboard = leaderboard.ok_or(TidesError::LeaderboardRequired)?;
+            require!(leaderboard.key() == season.leaderboard, TidesError::InvalidAddress);
+            update_leaderboard(leaderboard, season.season_id, stats.player, stats.net_value)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn update_leaderboard(
+        leaderboard: &AccountLoader<SeasonLeaderboard>,
+        season_id: u64,
+        player: Pubkey,
+        net_value: i64,
+    ) -> Result<()> {
+        let mut board = leaderboard.load_mut()?;
+        require!(board.season_id == season_id, TidesError::InvalidId);
+
+        let (old_rank, new_rank) = board.upsert(player, net_value);
+        if let Some(new_rank) = new_rank {
+            if old_rank != Some(new_rank) {
+                emit!(LeaderboardChanged {
+                    season_id,
+                    player,
+                    net_value,
+                    old_rank: old_rank.map(|rank| rank as u32 + 1),
+                    new_rank: new_rank as u32 + 1,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn create_track(
+        track: &mut Account<SeasonTrack>,
+        season_id: u64,
+        xp_per_catch: u64,
+        xp_per_sale: u64,
+        xp_per_hex: u64,
+        tiers: Vec<TrackTier>,
+    ) -> Result<()> {
+        require!(
+            !tiers.is_empty() && tiers.len() <= MAX_TRACK_TIERS,
+            TidesError::InvalidTrackTiers
+        );
+        require!(
+            tiers.windows(2).all(|pair| pair[0].xp_required < pair[1].xp_required),
+            TidesError::InvalidTrackTiers
+        );
+
+        track.season_id = season_id;
+        track.xp_per_catch = xp_per_catch;
+        track.xp_per_sale = xp_per_sale;
+        track.xp_per_hex = xp_per_hex;
+        track.tiers = tiers;
+        track.version = ACCOUNT_VERSION;
+
+        Ok(())
+    }
+
+    /// Adds XP to the player's season stats when they have joined the
+    /// season, it is running and it has a track. Returns the XP awarded.
+    pub fn award_xp(
+        season: Option<&Account<Season>>,
+        stats: Option<&mut PlayerSeasonStats>,
+        track: Option<&Account<SeasonTrack>>,
+        source: XpSource,
+        units: u64,
+    ) -> Result<u64> {
+        let stats = match stats {
+            Some(stats) => stats,
+            None => return Ok(0),
+        };
+        let season = season.ok_or(TidesError::SeasonAccountRequired)?;
+        require!(stats.season_id == season.season_id, TidesError::InvalidId);
+
+        // Seasons without a track have no tiers to progress through
+        let track = match track {
+            Some(track) => track,
+            None => return Ok(0),
+        };
+        require!(
+            track.key() == SeasonTrack::address(season.season_id),
+            TidesError::InvalidAddress
+        );
+
+        let clock = Clock::get()?;
+        if !is_season_active(season, &clock)? {
+            return Ok(0);
+        }
+
+        let xp = track.xp_for(source, units).ok_or(TidesError::MathOverflow)?;
+        stats.xp = stats.xp.checked_add(xp).ok_or(TidesError::MathOverflow)?;
+
+        if xp > 0 {
+            emit!(SeasonXpEarned {
+                season_id: season.season_id,
+                player: stats.player,
+                xp,
+                total_xp: stats.xp,
+            });
+        }
+
+        Ok(xp)
+    }
+
+    /// Marks `tier` as claimed in the requested lane and returns its reward.
+    /// Premium rewards need the player to still hold their pass token.
+    pub fn claim_tier(
+        stats: &mut Account<PlayerSeasonStats>,
+        track: &SeasonTrack,
+        pass_token_account: Option<&TokenAccount>,
+        tier: u8,
+        premium: bool,
+    ) -> Result<TierReward> {
+        let track_tier = track
+            .tiers
+            .get(tier as usize)
+            .ok_or(TidesError::InvalidTier)?;
+        require!(stats.xp >= track_tier.xp_required, TidesError::TierLocked);
+
+        let reward = track.reward(tier, premium);
+        require!(reward.kind != TierRewardKind::None, TidesError::NoTierReward);
+
+        let bit = 1u64 << tier;
+        let claimed = if premium {
+            require!(stats.has_pass, TidesError::NoSeasonPass);
+            let pass_token_account = pass_token_account.ok_or(TidesError::NoSeasonPass)?;
+            require!(
+                holds_pass(stats, pass_token_account, &stats.player),
+                TidesError::NoSeasonPass
+            );
+            &mut stats.premium_tiers_claimed
+        } else {
+            &mut stats.free_tiers_claimed
+        };
+        require!(*claimed & bit == 0, TidesError::TierAlreadyClaimed);
+        *claimed |= bit;
+
+        Ok(reward)
+    }
+
+    /// Fails while stats loaded from `info` are locked for rewards. Stats
+    /// that don't exist lock nothing.
+    pub fn require_unlocked(info: &AccountInfo, season: Option<&Account<Season>>, now: i64) -> Result<()> {
+        if let Some(stats) = load_current_stats(info)? {
+            let season = season.ok_or(TidesError::SeasonAccountRequired)?;
+            require!(season.season_id == stats.season_id, TidesError::InvalidId);
+            require!(!is_locked_for_rewards(&stats, season, now), TidesError::SeasonStatsLocked);
+        }
+        Ok(())
+    }
+
+    /// A pass holder's stats stay locked until their season is finalized
+    /// and its claim window has closed.
+    pub fn is_locked_for_rewards(stats: &PlayerSeasonStats, season: &Season, now: i64) -> bool {
+        stats.has_pass && (season.is_active || !season.is_finalized || now <= season.claim_deadline)
+    }
+
+    pub fn activate_season(season: &mut Account<Season>, clock: &Clock) -> Result<()> {
+        require!(!season.has_ended, TidesError::SeasonAlreadyEnded);
+        require!(!season.is_active, TidesError::SeasonAlreadyActive);
+        require!(clock.unix_timestamp < season.end_time, TidesError::SeasonHasEnded);
+        season.is_active = true;
+        Ok(())
+    }
+
+    pub fn update_season(
+        season: &mut Account<Season>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        pass_price: Option<u64>,
+        token_pass_price: Option<u64>,
+        prize_pool_bps: Option<u16>,
+        clock: &Clock,
+    ) -> Result<()> {
+        require!(!season.has_ended, TidesError::SeasonAlreadyEnded);
+
+        if let Some(start_time) = start_time {
+            // Moving the start of a running season would rewrite history
+            require!(!season.is_active, TidesError::SeasonAlreadyActive);
+            require!(start_time > clock.unix_timestamp, TidesError::StartTimeNotInFuture);
+            season.start_time = start_time;
+        }
+        if let Some(end_time) = end_time {
+            require!(end_time > clock.unix_timestamp, TidesError::InvalidTimeRange);
+            season.end_time = end_time;
+        }
+        require!(season.end_time > season.start_time, TidesError::InvalidTimeRange);
+
+        if let Some(pass_price) = pass_price {
+            season.pass_price = pass_price;
+        }
+        if let Some(token_pass_price) = token_pass_price {
+            season.token_pass_price = token_pass_price;
+        }
+        if let Some(prize_pool_bps) = prize_pool_bps {
+            // The split is fixed once passes can be sold
+            require!(!season.is_active, TidesError::SeasonAlreadyActive);
+            require!(prize_pool_bps as u64 <= BASIS_POINTS, TidesError::LimitOutOfBounds);
+            season.prize_pool_bps = prize_pool_bps;
+        }
+
+        Ok(())
+    }
+
+    /// Share of `pool` for zero-based `rank` when `count` ranks are paid on a
+    /// geometric curve: each rank gets `ratio_bps / 10000` of the rank above.
+    /// Rounding dust stays in the pool.
+    pub fn reward_share(pool: u64, rank: u32, count: u32, ratio_bps: u16) -> u64 {
+        if rank >= count {
+            return 0;
+        }
+
+        let scale: u128 = 1_000_000_000_000;
+        let mut weight = scale;
+        let mut rank_weight = 0u128;
+        let mut total_weight = 0u128;
+        for index in 0..count {
+            if index == rank {
+                rank_weight = weight;
+            }
+            total_weight += weight;
+            weight = weight * ratio_bps as u128 / BASIS_POINTS as u128;
+        }
+
+        if total_weight == 0 {
+            return 0;
+        }
+        (pool as u128 * rank_weight / total_weight) as u64
+    }
+
     pub fn end_season(season: &mut Account<Season>) -> Result<()> {
         require!(season.is_active, TidesError::SeasonAlreadyEnded);
         season.is_active = false;
+        season.has_ended = true;
         Ok(())
     }
 }
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::{TidesError, ACCOUNT_RESERVED_BYTES};
+
+// ============ Session Constants ============
+pub const MAX_SESSION_DURATION: i64 = 7 * 86_400; // 7 days
+
+// Instructions a session key may call, one bit each in `SessionToken.allowed_instructions`
+pub const SESSION_MOVE: u16 = 1 << 0;
+pub const SESSION_FISHING: u16 = 1 << 1;
+pub const SESSION_INVENTORY: u16 = 1 << 2;
+pub const SESSION_PURCHASE_FUEL: u16 = 1 << 3;
+pub const SESSION_PURCHASE_BAIT: u16 = 1 << 4;
+pub const SESSION_ALL: u16 = SESSION_MOVE
+    | SESSION_FISHING
+    | SESSION_INVENTORY
+    | SESSION_PURCHASE_FUEL
+    | SESSION_PURCHASE_BAIT;
+
+// ============ Session Account Structures ============
+
+/// Lets an ephemeral `session_key` act for `player` until `expires_at`, so
+/// gameplay does not need a wallet prompt per instruction. The PDA is also
+/// the SPL delegate of the player's token account for `spend_cap`.
+#[account]
+pub struct SessionToken {
+    pub player: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    pub spend_cap: u64, // Game tokens the session may spend in total
+    pub spent: u64,
+    pub allowed_instructions: u16, // SESSION_* bits
+    pub bump: u8,
+    pub version: u8,
+    pub _reserved: [u8; ACCOUNT_RESERVED_BYTES],
+}
+
+impl SessionToken {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // player
+        32 + // session_key
+        8 + // expires_at
+        8 + // spend_cap
+        8 + // spent
+        2 + // allowed_instructions
+        1 + // bump
+        1 + // version
+        ACCOUNT_RESERVED_BYTES; // _reserved
+}
+
+// ============ Session Module Functions ============
+
+pub mod session {
+    use super::*;
+
+    pub fn validate_session(
+        player: &Pubkey,
+        session_key: &Pubkey,
+        expires_at: i64,
+        allowed_instructions: u16,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            *session_key != Pubkey::default() && session_key != player,
+            TidesError::InvalidSession
+        );
+        require!(
+            expires_at > now && expires_at - now <= MAX_SESSION_DURATION,
+            TidesError::InvalidSession
+        );
+        require!(
+            allowed_instructions != 0 && allowed_instructions & !SESSION_ALL == 0,
+            TidesError::InvalidSession
+        );
+        Ok(())
+    }
+
+    /// Checks that `authority` may act for `player`: either it is the
+    /// player's wallet, or the key of an unexpired session that allows
+    /// `action`. Returns true when acting through the session.
+    pub fn authorize(
+        player: &Pubkey,
+        authority: &Pubkey,
+        session_token: Option<&Account<SessionToken>>,
+        action: u16,
+        now: i64,
+    ) -> Result<bool> {
+        if authority == player {
+            return Ok(false);
+        }
+
+        let session = session_token.ok_or(TidesError::InvalidPlayer)?;
+        require!(
+            session.player == *player && session.session_key == *authority,
+            TidesError::InvalidSession
+        );
+        require!(now < session.expires_at, TidesError::SessionExpired);
+        require!(
+            session.allowed_instructions & action != 0,
+            TidesError::SessionActionNotAllowed
+        );
+        Ok(true)
+    }
+
+    /// Transfers `amount` game tokens out of the player's account. The wallet
+    /// signs directly; through a session the session PDA signs as delegate
+    /// and the amount counts against the session's spend cap.
+    pub fn spend<'info>(
+        via_session: bool,
+        session_token: Option<&mut Account<'info, SessionToken>>,
+        authority: &Signer<'info>,
+        from: &Account<'info, TokenAccount>,
+        to: &Account<'info, TokenAccount>,
+        token_program: &Program<'info, Token>,
+        amount: u64,
+    ) -> Result<()> {
+        let cpi_program = token_program.to_account_info();
+
+        if !via_session {
+            let cpi_accounts = Transfer {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: authority.to_account_info(),
+            };
+            return token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount);
+        }
+
+        let session = session_token.ok_or(TidesError::InvalidPlayer)?;
+        session.spent = session
+            .spent
+            .checked_add(amount)
+            .ok_or(TidesError::MathOverflow)?;
+        require!(
+            session.spent <= session.spend_cap,
+            TidesError::SessionSpendCapExceeded
+        );
+
+        let seeds: &[&[u8]] = &[b"session", session.player.as_ref(), &[session.bump]];
+        let signer_seeds = &[seeds];
+        let cpi_accounts = Transfer {
+            from: from.to_account_info(),
+            to: to.to_account_info(),
+            authority: session.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
+            amount,
+        )
+    }
+}
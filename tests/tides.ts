@@ -10,9 +10,25 @@ describe("tides", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.Tides as Program<Tides>;
-  const gameStateKeypair = Keypair.generate();
   const playerKeypair = Keypair.generate();
 
+  const [game] = PublicKey.findProgramAddressSync(
+    [Buffer.from("game")],
+    program.programId
+  );
+  const [shardDirectory] = PublicKey.findProgramAddressSync(
+    [Buffer.from("shard_directory")],
+    program.programId
+  );
+  const [accessControl] = PublicKey.findProgramAddressSync(
+    [Buffer.from("access_control")],
+    program.programId
+  );
+  const [oracleSet] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle_set")],
+    program.programId
+  );
+
   it("Initializes the game", async () => {
     const currencyMint = Keypair.generate().publicKey;
     const serverSigner = Keypair.generate().publicKey;
@@ -21,21 +37,81 @@ describe("tides", () => {
     const tx = await program.methods
       .initialize(currencyMint, serverSigner, maxPlayersPerShard)
       .accounts({
-        gameState: gameStateKeypair.publicKey,
+        gameState: game,
+        shardDirectory,
+        accessControl,
+        oracleSet,
         admin: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
-      .signers([gameStateKeypair])
       .rpc();
 
-    const gameState = await program.account.gameState.fetch(
-      gameStateKeypair.publicKey
-    );
+    const gameState = await program.account.gameState.fetch(game);
 
     expect(gameState.currencyMint.toString()).to.equal(currencyMint.toString());
     expect(gameState.admin.toString()).to.equal(provider.wallet.publicKey.toString());
-    expect(gameState.paused).to.be.false;
+    expect(gameState.paused).to.equal(0);
     expect(gameState.maxPlayersPerShard.toNumber()).to.equal(1000);
+
+    const roles = await program.account.accessControl.fetch(accessControl);
+    expect(roles.members[0].account.toString()).to.equal(provider.wallet.publicKey.toString());
+
+    const oracles = await program.account.oracleSet.fetch(oracleSet);
+    expect(oracles.signers[0].key.toString()).to.equal(serverSigner.toString());
+  });
+
+  it("Grants and revokes a role", async () => {
+    const operator = Keypair.generate().publicKey;
+
+    await program.methods
+      .grantRole(operator, { shardOperator: {} })
+      .accounts({
+        accessControl,
+        admin: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    let roles = await program.account.accessControl.fetch(accessControl);
+    expect(roles.members.some((m) => m.account.equals(operator))).to.be.true;
+
+    await program.methods
+      .revokeRole(operator, { shardOperator: {} })
+      .accounts({
+        accessControl,
+        admin: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    roles = await program.account.accessControl.fetch(accessControl);
+    expect(roles.members.some((m) => m.account.equals(operator))).to.be.false;
+  });
+
+  it("Creates a shard", async () => {
+    const shard = 0;
+
+    const [shardData] = PublicKey.findProgramAddressSync(
+      [Buffer.from("shard"), Buffer.from([shard])],
+      program.programId
+    );
+
+    await program.methods
+      .createShard(shard)
+      .accounts({
+        gameState: game,
+        shardData: shardData,
+        shardDirectory,
+        accessControl,
+        admin: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const shardAccount = await program.account.shardData.fetch(shardData);
+    expect(shardAccount.shardId).to.equal(shard);
+    expect(shardAccount.playerCount.toNumber()).to.equal(0);
+
+    const directory = await program.account.shardDirectory.fetch(shardDirectory);
+    expect(directory.isOpen[shard]).to.be.true;
   });
 
   it("Registers a player", async () => {
@@ -46,13 +122,18 @@ describe("tides", () => {
       [Buffer.from("shard"), Buffer.from([shard])],
       program.programId
     );
+    const [playerState] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player"), playerKeypair.publicKey.toBuffer()],
+      program.programId
+    );
 
     const tx = await program.methods
       .registerPlayer(shard, mapId)
       .accounts({
-        gameState: gameStateKeypair.publicKey,
-        playerState: Keypair.generate().publicKey,
+        gameState: game,
+        playerState,
         shardData: shardData,
+        shardDirectory,
         player: playerKeypair.publicKey,
         systemProgram: SystemProgram.programId,
       })